@@ -0,0 +1,2078 @@
+#pragma once
+const char* const mnemonic_names[] = {"add","unsupported","or","adc","sbb","and","sub","xor","cmp","inc","dec","push","pop","movsxd","imul","ins","outs","jcc","test","xchg","mov","lea","nop","movsx","cwd","movs","cmps","stos","lods","scas","rol","ror","rcl","rcr","shl","shr","sar","ret","enter","leave","retf","int3","int","into","iret","loopne","loope","loop","jrcxz","in","out","call","jmp","hlt","cmc","not","neg","mul","div","idiv","clc","stc","cli","sti","cld","std","syscall","clts","sysret","invd","wbinvd","ud2","prefetch","prefetchw","prefetchwt1","prefetchnta","prefetcht0","prefetcht1","prefetcht2","endbr64","endbr32","wrmsr","rdtsc","rdmsr","rdpmc","sysenter","sysexit","cmovcc","setcc","cpuid","bt","shld","rsm","bts","shrd","cmpxchg","btr","movzx","ud1","btc","bsf","bsr","xadd","bswap","ud0","modrm_reg_opcode_ext","rep_prefix_modrm_ext",};
+const char* const op_size_names[] = {"8","16","32","64",};
+const char* const op_kind_names[] = {"imm","specific_imm","reg","rm","specific_reg","zext_specific_reg","addr_size_specific_reg","rel","mem_offset","implicit","cond",};
+const char* const reg_encoding_names[] = {"modrm","opcode",};
+const char* const specific_reg_names[] = {"rax","rdx","rcx","rbx","r11",};
+const char* const specific_imm_names[] = {"zero","one",};
+const char* const rep_kind_names[] = {"none","rep","repe_repne",};
+const op_size_info_t op_size_infos_table[] = {{.with_operand_size_override=OP_SIZE_8,.mode_32=OP_SIZE_8,.mode_64=OP_SIZE_8,.mode_64_with_rex_w=OP_SIZE_8,},
+{.with_operand_size_override=OP_SIZE_16,.mode_32=OP_SIZE_32,.mode_64=OP_SIZE_32,.mode_64_with_rex_w=OP_SIZE_64,},
+{.with_operand_size_override=OP_SIZE_16,.mode_32=OP_SIZE_32,.mode_64=OP_SIZE_32,.mode_64_with_rex_w=OP_SIZE_32,},
+{.with_operand_size_override=OP_SIZE_16,.mode_32=OP_SIZE_32,.mode_64=OP_SIZE_64,.mode_64_with_rex_w=OP_SIZE_64,},
+{.with_operand_size_override=OP_SIZE_8,.mode_32=OP_SIZE_16,.mode_64=OP_SIZE_16,.mode_64_with_rex_w=OP_SIZE_32,},
+{.with_operand_size_override=OP_SIZE_16,.mode_32=OP_SIZE_16,.mode_64=OP_SIZE_16,.mode_64_with_rex_w=OP_SIZE_16,},
+{.with_operand_size_override=OP_SIZE_64,.mode_32=OP_SIZE_64,.mode_64=OP_SIZE_64,.mode_64_with_rex_w=OP_SIZE_64,},
+{.with_operand_size_override=OP_SIZE_32,.mode_32=OP_SIZE_32,.mode_64=OP_SIZE_32,.mode_64_with_rex_w=OP_SIZE_32,},
+};
+const op_info_t op_infos_table[] = {{.rm={.kind=OP_KIND_RM,.size_info_index=0,},
+},
+{.reg={.kind=OP_KIND_REG,.size_info_index=0,.encoding=REG_ENC_MODRM,},
+},
+{.rm={.kind=OP_KIND_RM,.size_info_index=1,},
+},
+{.reg={.kind=OP_KIND_REG,.size_info_index=1,.encoding=REG_ENC_MODRM,},
+},
+{.specific_reg={.kind=OP_KIND_SPECIFIC_REG,.size_info_index=0,.reg=SPECIFIC_REG_RAX,},
+},
+{.imm={.kind=OP_KIND_IMM,.encoded_size_info_index=0,.extended_size_info_index=0,.extend_kind=IMM_EXT_KIND_SIGN_EXTEND,},
+},
+{.specific_reg={.kind=OP_KIND_SPECIFIC_REG,.size_info_index=1,.reg=SPECIFIC_REG_RAX,},
+},
+{.imm={.kind=OP_KIND_IMM,.encoded_size_info_index=2,.extended_size_info_index=1,.extend_kind=IMM_EXT_KIND_SIGN_EXTEND,},
+},
+{.reg={.kind=OP_KIND_REG,.size_info_index=1,.encoding=REG_ENC_OPCODE,},
+},
+{.reg={.kind=OP_KIND_REG,.size_info_index=3,.encoding=REG_ENC_OPCODE,},
+},
+{.rm={.kind=OP_KIND_RM,.size_info_index=2,},
+},
+{.imm={.kind=OP_KIND_IMM,.encoded_size_info_index=2,.extended_size_info_index=3,.extend_kind=IMM_EXT_KIND_SIGN_EXTEND,},
+},
+{.imm={.kind=OP_KIND_IMM,.encoded_size_info_index=0,.extended_size_info_index=3,.extend_kind=IMM_EXT_KIND_SIGN_EXTEND,},
+},
+{.imm={.kind=OP_KIND_IMM,.encoded_size_info_index=0,.extended_size_info_index=1,.extend_kind=IMM_EXT_KIND_SIGN_EXTEND,},
+},
+{.implicit={.kind=OP_KIND_IMPLICIT,.size_info_index=0,},
+},
+{.implicit={.kind=OP_KIND_IMPLICIT,.size_info_index=2,},
+},
+{.cond={.kind=OP_KIND_COND,},
+},
+{.rel={.kind=OP_KIND_REL,.size_info_index=0,},
+},
+{.rm={.kind=OP_KIND_RM,.size_info_index=3,},
+},
+{.specific_reg={.kind=OP_KIND_SPECIFIC_REG,.size_info_index=4,.reg=SPECIFIC_REG_RAX,},
+},
+{.specific_reg={.kind=OP_KIND_SPECIFIC_REG,.size_info_index=1,.reg=SPECIFIC_REG_RDX,},
+},
+{.mem_offset={.kind=OP_KIND_MEM_OFFSET,.mem_operand_size_info_index=0,},
+},
+{.mem_offset={.kind=OP_KIND_MEM_OFFSET,.mem_operand_size_info_index=1,},
+},
+{.implicit={.kind=OP_KIND_IMPLICIT,.size_info_index=1,},
+},
+{.reg={.kind=OP_KIND_REG,.size_info_index=0,.encoding=REG_ENC_OPCODE,},
+},
+{.imm={.kind=OP_KIND_IMM,.encoded_size_info_index=1,.extended_size_info_index=1,.extend_kind=IMM_EXT_KIND_ZERO_EXTEND,},
+},
+{.imm={.kind=OP_KIND_IMM,.encoded_size_info_index=0,.extended_size_info_index=1,.extend_kind=IMM_EXT_KIND_ZERO_EXTEND,},
+},
+{.imm={.kind=OP_KIND_IMM,.encoded_size_info_index=5,.extended_size_info_index=5,.extend_kind=IMM_EXT_KIND_ZERO_EXTEND,},
+},
+{.implicit={.kind=OP_KIND_IMPLICIT,.size_info_index=3,},
+},
+{.specific_imm={.kind=OP_KIND_SPECIFIC_IMM,.operand_size_info_index=0,.value=SPECIFIC_IMM_ONE,},
+},
+{.specific_imm={.kind=OP_KIND_SPECIFIC_IMM,.operand_size_info_index=1,.value=SPECIFIC_IMM_ONE,},
+},
+{.specific_reg={.kind=OP_KIND_SPECIFIC_REG,.size_info_index=0,.reg=SPECIFIC_REG_RCX,},
+},
+{.zext_specific_reg={.kind=OP_KIND_ZEXT_SPECIFIC_REG,.size_info_index=0,.extended_size_info_index=1,.reg=SPECIFIC_REG_RCX,},
+},
+{.addr_size_specific_reg={.kind=OP_KIND_ADDR_SIZE_SPECIFIC_REG,.reg=SPECIFIC_REG_RCX,},
+},
+{.specific_reg={.kind=OP_KIND_SPECIFIC_REG,.size_info_index=2,.reg=SPECIFIC_REG_RAX,},
+},
+{.rel={.kind=OP_KIND_REL,.size_info_index=2,},
+},
+{.specific_reg={.kind=OP_KIND_SPECIFIC_REG,.size_info_index=5,.reg=SPECIFIC_REG_RDX,},
+},
+{.specific_reg={.kind=OP_KIND_SPECIFIC_REG,.size_info_index=6,.reg=SPECIFIC_REG_RCX,},
+},
+{.specific_reg={.kind=OP_KIND_SPECIFIC_REG,.size_info_index=6,.reg=SPECIFIC_REG_R11,},
+},
+{.specific_reg={.kind=OP_KIND_SPECIFIC_REG,.size_info_index=1,.reg=SPECIFIC_REG_RCX,},
+},
+{.specific_reg={.kind=OP_KIND_SPECIFIC_REG,.size_info_index=7,.reg=SPECIFIC_REG_RCX,},
+},
+{.specific_reg={.kind=OP_KIND_SPECIFIC_REG,.size_info_index=7,.reg=SPECIFIC_REG_RDX,},
+},
+{.specific_reg={.kind=OP_KIND_SPECIFIC_REG,.size_info_index=7,.reg=SPECIFIC_REG_RAX,},
+},
+{.specific_reg={.kind=OP_KIND_SPECIFIC_REG,.size_info_index=7,.reg=SPECIFIC_REG_RBX,},
+},
+{.rm={.kind=OP_KIND_RM,.size_info_index=5,},
+},
+{.reg={.kind=OP_KIND_REG,.size_info_index=7,.encoding=REG_ENC_MODRM,},
+},
+{.rm={.kind=OP_KIND_RM,.size_info_index=7,},
+},
+};
+const uint8_t laid_out_ops_infos_table[] = {1,
+0,
+5,
+34,
+5,
+9,
+3,
+10,
+11,
+12,
+3,
+2,
+13,
+14,
+15,
+16,
+17,
+18,
+20,
+6,
+7,
+6,
+22,
+6,
+8,
+25,
+23,
+24,
+5,
+2,
+26,
+27,
+5,
+28,
+2,
+30,
+2,
+32,
+33,
+17,
+34,
+36,
+34,
+37,
+38,
+42,
+41,
+40,
+20,
+39,
+38,
+16,
+3,
+2,
+7,
+16,
+35,
+16,
+0,
+29,
+42,
+43,
+40,
+41,
+42,
+40,
+2,
+3,
+26,
+2,
+3,
+32,
+0,
+1,
+4,
+21,
+4,
+5,
+4,
+36,
+4,
+2,
+3,
+6,
+19,
+3,
+0,
+31,
+3,
+44,
+45,
+46,
+2,
+5,
+};
+const modrm_reg_opcode_ext_table_t modrm_reg_opcode_ext_tables[] = {{.by_reg_value={{.mnemonic=MNEMONIC_ADD,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_OR,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_ADC,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_SBB,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_AND,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_SUB,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_XOR,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_CMP,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_ADD,.first_op_index=53,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_OR,.first_op_index=53,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_ADC,.first_op_index=53,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_SBB,.first_op_index=53,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_AND,.first_op_index=53,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_SUB,.first_op_index=53,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_XOR,.first_op_index=53,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_CMP,.first_op_index=53,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_ADD,.first_op_index=11,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_OR,.first_op_index=11,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_ADC,.first_op_index=11,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_SBB,.first_op_index=11,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_AND,.first_op_index=11,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_SUB,.first_op_index=11,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_XOR,.first_op_index=11,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_CMP,.first_op_index=11,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_POP,.first_op_index=17,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_ROL,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_ROR,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_RCL,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_RCR,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SHL,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SHR,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SAR,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_ROL,.first_op_index=29,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_ROR,.first_op_index=29,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_RCL,.first_op_index=29,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_RCR,.first_op_index=29,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SHL,.first_op_index=29,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SHR,.first_op_index=29,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SAR,.first_op_index=29,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_MOV,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_MOV,.first_op_index=53,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_ROL,.first_op_index=58,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_ROR,.first_op_index=58,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_RCL,.first_op_index=58,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_RCR,.first_op_index=58,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SHL,.first_op_index=58,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SHR,.first_op_index=58,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SAR,.first_op_index=58,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_ROL,.first_op_index=34,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_ROR,.first_op_index=34,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_RCL,.first_op_index=34,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_RCR,.first_op_index=34,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SHL,.first_op_index=34,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SHR,.first_op_index=34,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SAR,.first_op_index=34,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_ROL,.first_op_index=86,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_ROR,.first_op_index=86,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_RCL,.first_op_index=86,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_RCR,.first_op_index=86,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SHL,.first_op_index=86,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SHR,.first_op_index=86,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SAR,.first_op_index=86,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_ROL,.first_op_index=36,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_ROR,.first_op_index=36,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_RCL,.first_op_index=36,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_RCR,.first_op_index=36,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SHL,.first_op_index=36,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SHR,.first_op_index=36,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_SAR,.first_op_index=36,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_TEST,.first_op_index=1,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_NOT,.first_op_index=1,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_NEG,.first_op_index=1,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_MUL,.first_op_index=1,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_IMUL,.first_op_index=1,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_DIV,.first_op_index=1,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_IDIV,.first_op_index=1,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_TEST,.first_op_index=53,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_NOT,.first_op_index=11,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_NEG,.first_op_index=11,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_MUL,.first_op_index=11,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_IMUL,.first_op_index=11,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_DIV,.first_op_index=11,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_IDIV,.first_op_index=11,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_INC,.first_op_index=1,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_DEC,.first_op_index=1,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_INC,.first_op_index=11,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_DEC,.first_op_index=11,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_CALL,.first_op_index=17,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_JMP,.first_op_index=17,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_PUSH,.first_op_index=17,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_PREFETCH,.first_op_index=1,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_PREFETCHW,.first_op_index=1,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_PREFETCHWT1,.first_op_index=1,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_NOP,.first_op_index=7,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_NOP,.first_op_index=7,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_NOP,.first_op_index=7,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_NOP,.first_op_index=7,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_NOP,.first_op_index=7,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_PREFETCHNTA,.first_op_index=1,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_PREFETCHT0,.first_op_index=1,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_PREFETCHT1,.first_op_index=1,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_PREFETCHT2,.first_op_index=1,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_NOP,.first_op_index=7,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_NOP,.first_op_index=7,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_NOP,.first_op_index=7,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_NOP,.first_op_index=7,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+},
+{.by_reg_value={{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_BT,.first_op_index=92,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+{.mnemonic=MNEMONIC_BTS,.first_op_index=92,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_BTR,.first_op_index=92,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+{.mnemonic=MNEMONIC_BTC,.first_op_index=92,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+},
+};
+const rep_prefix_modrm_override_t rep_prefix_modrm_overrides_table[] = {{.modrm=250,.insn={.mnemonic=MNEMONIC_ENDBR64,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.modrm=251,.insn={.mnemonic=MNEMONIC_ENDBR32,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+};
+const rep_prefix_modrm_ext_table_t rep_prefix_modrm_ext_tables[] = {{.default_insn={.mnemonic=MNEMONIC_NOP,.first_op_index=7,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+.first_override_index=0,.overrides_amount=2,},
+};
+const insn_info_t first_opcode_byte_table[] = {{.regular={.mnemonic=MNEMONIC_ADD,.first_op_index=72,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_ADD,.first_op_index=66,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_ADD,.first_op_index=0,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_ADD,.first_op_index=10,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_ADD,.first_op_index=76,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_ADD,.first_op_index=19,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_OR,.first_op_index=72,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_OR,.first_op_index=66,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_OR,.first_op_index=0,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_OR,.first_op_index=10,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_OR,.first_op_index=76,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_OR,.first_op_index=19,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_ADC,.first_op_index=72,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_ADC,.first_op_index=66,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_ADC,.first_op_index=0,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_ADC,.first_op_index=10,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_ADC,.first_op_index=76,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_ADC,.first_op_index=19,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SBB,.first_op_index=72,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_SBB,.first_op_index=66,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_SBB,.first_op_index=0,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SBB,.first_op_index=10,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SBB,.first_op_index=76,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SBB,.first_op_index=19,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_AND,.first_op_index=72,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_AND,.first_op_index=66,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_AND,.first_op_index=0,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_AND,.first_op_index=10,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_AND,.first_op_index=76,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_AND,.first_op_index=19,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SUB,.first_op_index=72,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_SUB,.first_op_index=66,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_SUB,.first_op_index=0,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SUB,.first_op_index=10,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SUB,.first_op_index=76,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SUB,.first_op_index=19,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_XOR,.first_op_index=72,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_XOR,.first_op_index=66,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_XOR,.first_op_index=0,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_XOR,.first_op_index=10,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_XOR,.first_op_index=76,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_XOR,.first_op_index=19,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMP,.first_op_index=72,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMP,.first_op_index=66,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMP,.first_op_index=0,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMP,.first_op_index=10,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMP,.first_op_index=76,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMP,.first_op_index=19,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_INC,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_INC,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_INC,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_INC,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_INC,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_INC,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_INC,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_INC,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_DEC,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_DEC,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_DEC,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_DEC,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_DEC,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_DEC,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_DEC,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_DEC,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_PUSH,.first_op_index=5,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_PUSH,.first_op_index=5,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_PUSH,.first_op_index=5,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_PUSH,.first_op_index=5,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_PUSH,.first_op_index=5,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_PUSH,.first_op_index=5,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_PUSH,.first_op_index=5,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_PUSH,.first_op_index=5,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_POP,.first_op_index=5,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_POP,.first_op_index=5,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_POP,.first_op_index=5,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_POP,.first_op_index=5,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_POP,.first_op_index=5,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_POP,.first_op_index=5,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_POP,.first_op_index=5,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_POP,.first_op_index=5,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOVSXD,.first_op_index=6,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_PUSH,.first_op_index=8,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_IMUL,.first_op_index=52,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_PUSH,.first_op_index=9,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_IMUL,.first_op_index=10,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_INS,.first_op_index=13,.ops_amount=1,.rep_kind=REP_KIND_REP,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_INS,.first_op_index=14,.ops_amount=1,.rep_kind=REP_KIND_REP,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_OUTS,.first_op_index=13,.ops_amount=1,.rep_kind=REP_KIND_REP,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_OUTS,.first_op_index=14,.ops_amount=1,.rep_kind=REP_KIND_REP,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=15,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=15,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=15,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=15,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=15,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=15,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=15,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=15,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=15,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=15,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=15,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=15,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=15,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=15,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=15,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=15,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=0,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=1,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=2,},
+},
+{.regular={.mnemonic=MNEMONIC_TEST,.first_op_index=72,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_TEST,.first_op_index=66,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_XCHG,.first_op_index=72,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_XCHG,.first_op_index=66,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=72,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=66,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=0,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=10,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_LEA,.first_op_index=10,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=3,},
+},
+{.regular={.mnemonic=MNEMONIC_NOP,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_XCHG,.first_op_index=23,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_XCHG,.first_op_index=23,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_XCHG,.first_op_index=23,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_XCHG,.first_op_index=23,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_XCHG,.first_op_index=23,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_XCHG,.first_op_index=23,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_XCHG,.first_op_index=23,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOVSX,.first_op_index=83,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CWD,.first_op_index=18,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=74,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=21,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=75,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=22,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOVS,.first_op_index=13,.ops_amount=1,.rep_kind=REP_KIND_REP,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOVS,.first_op_index=26,.ops_amount=1,.rep_kind=REP_KIND_REP,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMPS,.first_op_index=13,.ops_amount=1,.rep_kind=REP_KIND_REPE_REPNE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMPS,.first_op_index=26,.ops_amount=1,.rep_kind=REP_KIND_REPE_REPNE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_TEST,.first_op_index=76,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_TEST,.first_op_index=19,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_STOS,.first_op_index=13,.ops_amount=1,.rep_kind=REP_KIND_REP,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_STOS,.first_op_index=26,.ops_amount=1,.rep_kind=REP_KIND_REP,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_LODS,.first_op_index=13,.ops_amount=1,.rep_kind=REP_KIND_REP,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_LODS,.first_op_index=26,.ops_amount=1,.rep_kind=REP_KIND_REP,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SCAS,.first_op_index=13,.ops_amount=1,.rep_kind=REP_KIND_REPE_REPNE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SCAS,.first_op_index=26,.ops_amount=1,.rep_kind=REP_KIND_REPE_REPNE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=27,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=27,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=27,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=27,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=27,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=27,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=27,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=27,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=24,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=24,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=24,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=24,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=24,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=24,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=24,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOV,.first_op_index=24,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=4,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=5,},
+},
+{.regular={.mnemonic=MNEMONIC_RET,.first_op_index=31,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_RET,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=6,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=7,},
+},
+{.regular={.mnemonic=MNEMONIC_ENTER,.first_op_index=31,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_LEAVE,.first_op_index=33,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_RETF,.first_op_index=31,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_RETF,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_INT3,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_INT,.first_op_index=2,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_INTO,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_IRET,.first_op_index=26,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=8,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=9,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=10,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=11,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_LOOPNE,.first_op_index=38,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_LOOPE,.first_op_index=38,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_LOOP,.first_op_index=38,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JRCXZ,.first_op_index=38,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_IN,.first_op_index=76,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_IN,.first_op_index=3,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_OUT,.first_op_index=77,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_OUT,.first_op_index=2,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CALL,.first_op_index=56,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JMP,.first_op_index=56,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JMP,.first_op_index=16,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_IN,.first_op_index=78,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_IN,.first_op_index=40,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_OUT,.first_op_index=79,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_OUT,.first_op_index=41,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_HLT,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMC,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=12,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=13,},
+},
+{.regular={.mnemonic=MNEMONIC_CLC,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_STC,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CLI,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_STI,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CLD,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_STD,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=14,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=15,},
+},
+};
+const insn_info_t second_opcode_byte_table[] = {{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SYSCALL,.first_op_index=43,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CLTS,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SYSRET,.first_op_index=49,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_INVD,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_WBINVD,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UD2,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=16,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=17,},
+},
+{.regular={.mnemonic=MNEMONIC_NOP,.first_op_index=7,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_NOP,.first_op_index=7,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_NOP,.first_op_index=7,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_NOP,.first_op_index=7,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_NOP,.first_op_index=7,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.rep_prefix_modrm_ext={.mnemonic=MNEMONIC_REP_PREFIX_MODRM_EXT,.rep_prefix_modrm_table_index=0,},
+},
+{.regular={.mnemonic=MNEMONIC_NOP,.first_op_index=7,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_WRMSR,.first_op_index=62,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_RDTSC,.first_op_index=63,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_RDMSR,.first_op_index=63,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_RDPMC,.first_op_index=45,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SYSENTER,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SYSEXIT,.first_op_index=48,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMOVCC,.first_op_index=51,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMOVCC,.first_op_index=51,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMOVCC,.first_op_index=51,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMOVCC,.first_op_index=51,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMOVCC,.first_op_index=51,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMOVCC,.first_op_index=51,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMOVCC,.first_op_index=51,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMOVCC,.first_op_index=51,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMOVCC,.first_op_index=51,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMOVCC,.first_op_index=51,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMOVCC,.first_op_index=51,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMOVCC,.first_op_index=51,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMOVCC,.first_op_index=51,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMOVCC,.first_op_index=51,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMOVCC,.first_op_index=51,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMOVCC,.first_op_index=51,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=55,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=55,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=55,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=55,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=55,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=55,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=55,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=55,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=55,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=55,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=55,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=55,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=55,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=55,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=55,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_JCC,.first_op_index=55,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SETCC,.first_op_index=57,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SETCC,.first_op_index=57,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SETCC,.first_op_index=57,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SETCC,.first_op_index=57,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SETCC,.first_op_index=57,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SETCC,.first_op_index=57,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SETCC,.first_op_index=57,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SETCC,.first_op_index=57,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SETCC,.first_op_index=57,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SETCC,.first_op_index=57,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SETCC,.first_op_index=57,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SETCC,.first_op_index=57,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SETCC,.first_op_index=57,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SETCC,.first_op_index=57,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SETCC,.first_op_index=57,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SETCC,.first_op_index=57,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CPUID,.first_op_index=60,.ops_amount=4,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_BT,.first_op_index=66,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SHLD,.first_op_index=66,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SHLD,.first_op_index=69,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_RSM,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_BTS,.first_op_index=66,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_SHRD,.first_op_index=66,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_SHRD,.first_op_index=69,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_IMUL,.first_op_index=10,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_CMPXCHG,.first_op_index=72,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_CMPXCHG,.first_op_index=81,.ops_amount=3,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_BTR,.first_op_index=66,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOVZX,.first_op_index=85,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOVZX,.first_op_index=88,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UD1,.first_op_index=90,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.modrm_reg_opcode_ext={.mnemonic=MNEMONIC_MODRM_REG_OPCODE_EXT,.modrm_reg_table_index=18,},
+},
+{.regular={.mnemonic=MNEMONIC_BTC,.first_op_index=66,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_BSF,.first_op_index=10,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_BSR,.first_op_index=10,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOVSX,.first_op_index=85,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_MOVSX,.first_op_index=88,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_XADD,.first_op_index=72,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_XADD,.first_op_index=66,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=1,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_BSWAP,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_BSWAP,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_BSWAP,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_BSWAP,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_BSWAP,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_BSWAP,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_BSWAP,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_BSWAP,.first_op_index=24,.ops_amount=1,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UNSUPPORTED,.first_op_index=0,.ops_amount=0,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+{.regular={.mnemonic=MNEMONIC_UD0,.first_op_index=90,.ops_amount=2,.rep_kind=REP_KIND_NONE,.lockable=0,},
+},
+};
+const uint8_t first_opcode_byte_supported[] = {63,
+63,
+63,
+63,
+63,
+63,
+63,
+63,
+255,
+255,
+255,
+255,
+8,
+255,
+255,
+255,
+251,
+175,
+255,
+3,
+255,
+255,
+255,
+255,
+207,
+255,
+15,
+0,
+255,
+251,
+240,
+255,
+};
+const uint8_t first_opcode_byte_has_modrm[] = {15,
+15,
+15,
+15,
+15,
+15,
+15,
+15,
+0,
+0,
+0,
+0,
+8,
+10,
+0,
+0,
+251,
+175,
+0,
+0,
+0,
+0,
+0,
+0,
+195,
+0,
+15,
+0,
+0,
+0,
+192,
+192,
+};
+const imm_size_info_t first_opcode_byte_imm_size_by_mode[] = {{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=1,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=1,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=1,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=1,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=8,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=8,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=8,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=8,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=8,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=8,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=8,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=8,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=2,.mode_64=2,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=2,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=3,.mode_32=3,.mode_64=3,.mode_64_with_operand_size_override=3,.mode_64_with_rex_w=3,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=2,.mode_64=2,.mode_64_with_operand_size_override=2,.mode_64_with_rex_w=2,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=1,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=1,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+};
+const uint8_t second_opcode_byte_supported[] = {224,
+43,
+0,
+255,
+0,
+0,
+63,
+0,
+255,
+255,
+0,
+0,
+0,
+0,
+0,
+0,
+255,
+255,
+255,
+255,
+60,
+188,
+203,
+254,
+3,
+255,
+0,
+0,
+0,
+0,
+0,
+128,
+};
+const uint8_t second_opcode_byte_has_modrm[] = {0,
+32,
+0,
+255,
+0,
+0,
+0,
+0,
+255,
+255,
+0,
+0,
+0,
+0,
+0,
+0,
+0,
+0,
+255,
+255,
+56,
+184,
+203,
+254,
+3,
+0,
+0,
+0,
+0,
+0,
+0,
+128,
+};
+const imm_size_info_t second_opcode_byte_imm_size_by_mode[] = {{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=2,.mode_32=4,.mode_64=4,.mode_64_with_operand_size_override=4,.mode_64_with_rex_w=4,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=1,.mode_32=1,.mode_64=1,.mode_64_with_operand_size_override=1,.mode_64_with_rex_w=1,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+{.with_operand_size_override=0,.mode_32=0,.mode_64=0,.mode_64_with_operand_size_override=0,.mode_64_with_rex_w=0,.has_mem_offset=0,.depends_on_modrm=0,},
+};
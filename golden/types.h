@@ -0,0 +1,169 @@
+#pragma once
+#include <stdint.h>
+#define X86_TABLES_INSN_MAX_OPS 4
+typedef enum {MNEMONIC_ADD,MNEMONIC_UNSUPPORTED,MNEMONIC_OR,MNEMONIC_ADC,MNEMONIC_SBB,MNEMONIC_AND,MNEMONIC_SUB,MNEMONIC_XOR,MNEMONIC_CMP,MNEMONIC_INC,MNEMONIC_DEC,MNEMONIC_PUSH,MNEMONIC_POP,MNEMONIC_MOVSXD,MNEMONIC_IMUL,MNEMONIC_INS,MNEMONIC_OUTS,MNEMONIC_JCC,MNEMONIC_TEST,MNEMONIC_XCHG,MNEMONIC_MOV,MNEMONIC_LEA,MNEMONIC_NOP,MNEMONIC_MOVSX,MNEMONIC_CWD,MNEMONIC_MOVS,MNEMONIC_CMPS,MNEMONIC_STOS,MNEMONIC_LODS,MNEMONIC_SCAS,MNEMONIC_ROL,MNEMONIC_ROR,MNEMONIC_RCL,MNEMONIC_RCR,MNEMONIC_SHL,MNEMONIC_SHR,MNEMONIC_SAR,MNEMONIC_RET,MNEMONIC_ENTER,MNEMONIC_LEAVE,MNEMONIC_RETF,MNEMONIC_INT3,MNEMONIC_INT,MNEMONIC_INTO,MNEMONIC_IRET,MNEMONIC_LOOPNE,MNEMONIC_LOOPE,MNEMONIC_LOOP,MNEMONIC_JRCXZ,MNEMONIC_IN,MNEMONIC_OUT,MNEMONIC_CALL,MNEMONIC_JMP,MNEMONIC_HLT,MNEMONIC_CMC,MNEMONIC_NOT,MNEMONIC_NEG,MNEMONIC_MUL,MNEMONIC_DIV,MNEMONIC_IDIV,MNEMONIC_CLC,MNEMONIC_STC,MNEMONIC_CLI,MNEMONIC_STI,MNEMONIC_CLD,MNEMONIC_STD,MNEMONIC_SYSCALL,MNEMONIC_CLTS,MNEMONIC_SYSRET,MNEMONIC_INVD,MNEMONIC_WBINVD,MNEMONIC_UD2,MNEMONIC_PREFETCH,MNEMONIC_PREFETCHW,MNEMONIC_PREFETCHWT1,MNEMONIC_PREFETCHNTA,MNEMONIC_PREFETCHT0,MNEMONIC_PREFETCHT1,MNEMONIC_PREFETCHT2,MNEMONIC_ENDBR64,MNEMONIC_ENDBR32,MNEMONIC_WRMSR,MNEMONIC_RDTSC,MNEMONIC_RDMSR,MNEMONIC_RDPMC,MNEMONIC_SYSENTER,MNEMONIC_SYSEXIT,MNEMONIC_CMOVCC,MNEMONIC_SETCC,MNEMONIC_CPUID,MNEMONIC_BT,MNEMONIC_SHLD,MNEMONIC_RSM,MNEMONIC_BTS,MNEMONIC_SHRD,MNEMONIC_CMPXCHG,MNEMONIC_BTR,MNEMONIC_MOVZX,MNEMONIC_UD1,MNEMONIC_BTC,MNEMONIC_BSF,MNEMONIC_BSR,MNEMONIC_XADD,MNEMONIC_BSWAP,MNEMONIC_UD0,MNEMONIC_MODRM_REG_OPCODE_EXT,MNEMONIC_REP_PREFIX_MODRM_EXT,MNEMONIC_MAX = MNEMONIC_REP_PREFIX_MODRM_EXT,}mnemonic_t;
+typedef enum {REP_KIND_NONE,REP_KIND_REP,REP_KIND_REPE_REPNE,REP_KIND_MAX = REP_KIND_REPE_REPNE,}rep_kind_t;
+typedef struct __attribute__((packed)) {
+uint8_t mnemonic: 7;
+uint8_t first_op_index: 7;
+uint8_t ops_amount: 3;
+uint8_t rep_kind: 2;
+uint8_t lockable: 1;
+}regular_insn_info_t;
+#define REGULAR_INSN_INFO_MNEMONIC(p) ((p)->mnemonic)
+#define REGULAR_INSN_INFO_FIRST_OP_INDEX(p) ((p)->first_op_index)
+#define REGULAR_INSN_INFO_OPS_AMOUNT(p) ((p)->ops_amount)
+#define REGULAR_INSN_INFO_REP_KIND(p) ((p)->rep_kind)
+#define REGULAR_INSN_INFO_LOCKABLE(p) ((p)->lockable)
+typedef union __attribute__((packed)) {
+uint8_t mnemonic: 7;
+regular_insn_info_t regular;
+struct __attribute__((packed)) {
+uint8_t mnemonic: 7;
+uint8_t modrm_reg_table_index: 5;
+}modrm_reg_opcode_ext;
+struct __attribute__((packed)) {
+uint8_t mnemonic: 7;
+uint8_t rep_prefix_modrm_table_index: 1;
+}rep_prefix_modrm_ext;
+}insn_info_t;
+#define INSN_INFO_MNEMONIC(p) ((p)->mnemonic)
+#define INSN_INFO_REGULAR(p) (&(p)->regular)
+#define INSN_INFO_MODRM_REG_OPCODE_EXT_MNEMONIC(p) ((p)->modrm_reg_opcode_ext.mnemonic)
+#define INSN_INFO_MODRM_REG_OPCODE_EXT_MODRM_REG_TABLE_INDEX(p) ((p)->modrm_reg_opcode_ext.modrm_reg_table_index)
+#define INSN_INFO_REP_PREFIX_MODRM_EXT_MNEMONIC(p) ((p)->rep_prefix_modrm_ext.mnemonic)
+#define INSN_INFO_REP_PREFIX_MODRM_EXT_REP_PREFIX_MODRM_TABLE_INDEX(p) ((p)->rep_prefix_modrm_ext.rep_prefix_modrm_table_index)
+typedef enum {OP_SIZE_8,OP_SIZE_16,OP_SIZE_32,OP_SIZE_64,OP_SIZE_MAX = OP_SIZE_64,}op_size_t;
+typedef struct __attribute__((packed)) {
+uint8_t with_operand_size_override: 2;
+uint8_t mode_32: 2;
+uint8_t mode_64: 2;
+uint8_t mode_64_with_rex_w: 2;
+}op_size_info_t;
+#define OP_SIZE_INFO_WITH_OPERAND_SIZE_OVERRIDE(p) ((p)->with_operand_size_override)
+#define OP_SIZE_INFO_MODE_32(p) ((p)->mode_32)
+#define OP_SIZE_INFO_MODE_64(p) ((p)->mode_64)
+#define OP_SIZE_INFO_MODE_64_WITH_REX_W(p) ((p)->mode_64_with_rex_w)
+typedef enum {OP_KIND_IMM,OP_KIND_SPECIFIC_IMM,OP_KIND_REG,OP_KIND_RM,OP_KIND_SPECIFIC_REG,OP_KIND_ZEXT_SPECIFIC_REG,OP_KIND_ADDR_SIZE_SPECIFIC_REG,OP_KIND_REL,OP_KIND_MEM_OFFSET,OP_KIND_IMPLICIT,OP_KIND_COND,OP_KIND_MAX = OP_KIND_COND,}op_kind_t;
+typedef enum {REG_ENC_MODRM,REG_ENC_OPCODE,REG_ENC_MAX = REG_ENC_OPCODE,}reg_encoding_t;
+typedef enum {SPECIFIC_REG_RAX,SPECIFIC_REG_RDX,SPECIFIC_REG_RCX,SPECIFIC_REG_RBX,SPECIFIC_REG_R11,SPECIFIC_REG_MAX = SPECIFIC_REG_R11,}specific_reg_t;
+typedef enum {SPECIFIC_IMM_ZERO,SPECIFIC_IMM_ONE,SPECIFIC_IMM_MAX = SPECIFIC_IMM_ONE,}specific_imm_t;
+typedef enum {IMM_EXT_KIND_SIGN_EXTEND,IMM_EXT_KIND_ZERO_EXTEND,IMM_EXT_KIND_MAX = IMM_EXT_KIND_ZERO_EXTEND,}imm_ext_kind_t;
+typedef union __attribute__((packed)) {
+uint8_t kind: 4;
+struct __attribute__((packed)) {
+uint8_t kind: 4;
+uint8_t encoded_size_info_index: 3;
+uint8_t extended_size_info_index: 3;
+uint8_t extend_kind: 1;
+}imm;
+struct __attribute__((packed)) {
+uint8_t kind: 4;
+uint8_t operand_size_info_index: 3;
+uint8_t value: 1;
+}specific_imm;
+struct __attribute__((packed)) {
+uint8_t kind: 4;
+uint8_t size_info_index: 3;
+uint8_t encoding: 1;
+}reg;
+struct __attribute__((packed)) {
+uint8_t kind: 4;
+uint8_t size_info_index: 3;
+}rm;
+struct __attribute__((packed)) {
+uint8_t kind: 4;
+uint8_t size_info_index: 3;
+uint8_t reg: 3;
+}specific_reg;
+struct __attribute__((packed)) {
+uint8_t kind: 4;
+uint8_t size_info_index: 3;
+uint8_t extended_size_info_index: 3;
+uint8_t reg: 3;
+}zext_specific_reg;
+struct __attribute__((packed)) {
+uint8_t kind: 4;
+uint8_t reg: 3;
+}addr_size_specific_reg;
+struct __attribute__((packed)) {
+uint8_t kind: 4;
+uint8_t size_info_index: 3;
+}rel;
+struct __attribute__((packed)) {
+uint8_t kind: 4;
+uint8_t mem_operand_size_info_index: 3;
+}mem_offset;
+struct __attribute__((packed)) {
+uint8_t kind: 4;
+uint8_t size_info_index: 3;
+}implicit;
+struct __attribute__((packed)) {
+uint8_t kind: 4;
+}cond;
+}op_info_t;
+#define OP_INFO_KIND(p) ((p)->kind)
+#define OP_INFO_IMM_KIND(p) ((p)->imm.kind)
+#define OP_INFO_IMM_ENCODED_SIZE_INFO_INDEX(p) ((p)->imm.encoded_size_info_index)
+#define OP_INFO_IMM_EXTENDED_SIZE_INFO_INDEX(p) ((p)->imm.extended_size_info_index)
+#define OP_INFO_IMM_EXTEND_KIND(p) ((p)->imm.extend_kind)
+#define OP_INFO_SPECIFIC_IMM_KIND(p) ((p)->specific_imm.kind)
+#define OP_INFO_SPECIFIC_IMM_OPERAND_SIZE_INFO_INDEX(p) ((p)->specific_imm.operand_size_info_index)
+#define OP_INFO_SPECIFIC_IMM_VALUE(p) ((p)->specific_imm.value)
+#define OP_INFO_REG_KIND(p) ((p)->reg.kind)
+#define OP_INFO_REG_SIZE_INFO_INDEX(p) ((p)->reg.size_info_index)
+#define OP_INFO_REG_ENCODING(p) ((p)->reg.encoding)
+#define OP_INFO_RM_KIND(p) ((p)->rm.kind)
+#define OP_INFO_RM_SIZE_INFO_INDEX(p) ((p)->rm.size_info_index)
+#define OP_INFO_SPECIFIC_REG_KIND(p) ((p)->specific_reg.kind)
+#define OP_INFO_SPECIFIC_REG_SIZE_INFO_INDEX(p) ((p)->specific_reg.size_info_index)
+#define OP_INFO_SPECIFIC_REG_REG(p) ((p)->specific_reg.reg)
+#define OP_INFO_ZEXT_SPECIFIC_REG_KIND(p) ((p)->zext_specific_reg.kind)
+#define OP_INFO_ZEXT_SPECIFIC_REG_SIZE_INFO_INDEX(p) ((p)->zext_specific_reg.size_info_index)
+#define OP_INFO_ZEXT_SPECIFIC_REG_EXTENDED_SIZE_INFO_INDEX(p) ((p)->zext_specific_reg.extended_size_info_index)
+#define OP_INFO_ZEXT_SPECIFIC_REG_REG(p) ((p)->zext_specific_reg.reg)
+#define OP_INFO_ADDR_SIZE_SPECIFIC_REG_KIND(p) ((p)->addr_size_specific_reg.kind)
+#define OP_INFO_ADDR_SIZE_SPECIFIC_REG_REG(p) ((p)->addr_size_specific_reg.reg)
+#define OP_INFO_REL_KIND(p) ((p)->rel.kind)
+#define OP_INFO_REL_SIZE_INFO_INDEX(p) ((p)->rel.size_info_index)
+#define OP_INFO_MEM_OFFSET_KIND(p) ((p)->mem_offset.kind)
+#define OP_INFO_MEM_OFFSET_MEM_OPERAND_SIZE_INFO_INDEX(p) ((p)->mem_offset.mem_operand_size_info_index)
+#define OP_INFO_IMPLICIT_KIND(p) ((p)->implicit.kind)
+#define OP_INFO_IMPLICIT_SIZE_INFO_INDEX(p) ((p)->implicit.size_info_index)
+#define OP_INFO_COND_KIND(p) ((p)->cond.kind)
+typedef struct __attribute__((packed)) {
+regular_insn_info_t by_reg_value[8];
+}modrm_reg_opcode_ext_table_t;
+#define MODRM_REG_OPCODE_EXT_TABLE_BY_REG_VALUE(p, i) (&(p)->by_reg_value[(i)])
+typedef struct __attribute__((packed)) {
+uint8_t modrm: 8;
+regular_insn_info_t insn;
+}rep_prefix_modrm_override_t;
+#define REP_PREFIX_MODRM_OVERRIDE_MODRM(p) ((p)->modrm)
+#define REP_PREFIX_MODRM_OVERRIDE_INSN(p) (&(p)->insn)
+typedef struct __attribute__((packed)) {
+regular_insn_info_t default_insn;
+uint8_t first_override_index: 1;
+uint8_t overrides_amount: 2;
+}rep_prefix_modrm_ext_table_t;
+#define REP_PREFIX_MODRM_EXT_TABLE_DEFAULT_INSN(p) (&(p)->default_insn)
+#define REP_PREFIX_MODRM_EXT_TABLE_FIRST_OVERRIDE_INDEX(p) ((p)->first_override_index)
+#define REP_PREFIX_MODRM_EXT_TABLE_OVERRIDES_AMOUNT(p) ((p)->overrides_amount)
+typedef struct __attribute__((packed)) {
+uint8_t with_operand_size_override: 4;
+uint8_t mode_32: 4;
+uint8_t mode_64: 4;
+uint8_t mode_64_with_operand_size_override: 4;
+uint8_t mode_64_with_rex_w: 4;
+uint8_t has_mem_offset: 1;
+uint8_t depends_on_modrm: 1;
+}imm_size_info_t;
+#define IMM_SIZE_INFO_WITH_OPERAND_SIZE_OVERRIDE(p) ((p)->with_operand_size_override)
+#define IMM_SIZE_INFO_MODE_32(p) ((p)->mode_32)
+#define IMM_SIZE_INFO_MODE_64(p) ((p)->mode_64)
+#define IMM_SIZE_INFO_MODE_64_WITH_OPERAND_SIZE_OVERRIDE(p) ((p)->mode_64_with_operand_size_override)
+#define IMM_SIZE_INFO_MODE_64_WITH_REX_W(p) ((p)->mode_64_with_rex_w)
+#define IMM_SIZE_INFO_HAS_MEM_OFFSET(p) ((p)->has_mem_offset)
+#define IMM_SIZE_INFO_DEPENDS_ON_MODRM(p) ((p)->depends_on_modrm)
+#define X86_TABLES_HAS_MODRM(has_modrm_table, opcode) (((has_modrm_table)[(opcode) >> 3] >> ((opcode) & 7)) & 1)
+#define X86_TABLES_IS_SUPPORTED(supported_table, opcode) (((supported_table)[(opcode) >> 3] >> ((opcode) & 7)) & 1)
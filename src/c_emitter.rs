@@ -1,11 +1,120 @@
+use std::cmp::{max, min};
+
+/// how the emitted tables are linked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableLinkage {
+    /// plain definitions, which may only be included by a single translation unit.
+    Default,
+    /// static definitions, so that every translation unit which includes them gets its own copy.
+    Static,
+    /// plain definitions, along with `extern` declarations of them which are collected separately, for a header.
+    Extern,
+}
+
+/// how the emitted structs and unions are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum StructLayout {
+    /// packed bitfields, which rely on the gcc `packed` attribute and on the implementation defined layout of bitfields
+    Packed,
+    /// plain byte arrays, in which the fields are laid out one after another starting from the least significant bit of
+    /// the first byte, so that the layout is the same with every compiler
+    Portable,
+}
+
+/// how the emitted code is formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum CodeStyle {
+    /// as little whitespace as possible
+    Compact,
+    /// indented, with every table entry on its own line along with a comment describing it
+    Pretty,
+}
+
+/// a field of an emitted struct or union.
+#[derive(Clone)]
+struct LayoutField {
+    /// the path of the field, in which the names of embedded structs are separated by a `.`.
+    path: String,
+    /// the offset of the field in bits.
+    offset: usize,
+    kind: LayoutFieldKind,
+}
+
+#[derive(Clone)]
+enum LayoutFieldKind {
+    /// a bit field of the given amount of bits.
+    Bits(usize),
+    /// an integer of the given amount of bits.
+    Int(usize),
+    /// a struct or union declared by the emitter.
+    Struct(String),
+    /// an array of structs or unions declared by the emitter, of the given length.
+    Array(String, usize),
+}
+
+/// the layout of an emitted struct or union, which is used to generate its accessors, and to pack the values of its table
+/// entries in the portable layout.
+#[derive(Clone)]
+struct Layout {
+    /// the size in bytes.
+    size: usize,
+    fields: Vec<LayoutField>,
+}
+
+/// the size of an emitted table.
+#[derive(Clone)]
+pub struct TableStats {
+    pub name: String,
+    pub entries: usize,
+    /// the size in bytes. tables of strings are assumed to consist of 8 byte pointers, along with the strings themselves.
+    pub size: usize,
+}
+
+/// a bit field of a declared struct or union.
+pub struct BitFieldStats {
+    pub type_name: String,
+    /// the path of the field, in which the names of embedded structs are separated by a `.`.
+    pub path: String,
+    pub bits: usize,
+}
+
 /// C code emitter
 pub struct CEmitter {
     code: String,
+    /// a prefix which is applied to the name of every declared symbol, so that multiple generated copies can live in a
+    /// single program.
+    symbol_prefix: String,
+    table_linkage: TableLinkage,
+    /// the `extern` declarations of the emitted tables, if their linkage is `TableLinkage::Extern`.
+    table_declarations: String,
+    struct_layout: StructLayout,
+    /// the layouts of the declared structs and unions, along with their prefixed names, in the order of their
+    /// declaration.
+    layouts: Vec<(String, Layout)>,
+    table_stats: Vec<TableStats>,
+    /// the fields of the struct or union which is currently being declared.
+    pending_fields: Vec<LayoutField>,
+    /// the values of the table entry which is currently being emitted in the portable layout, by the paths of their
+    /// fields.
+    pending_values: Vec<(String, String)>,
+    code_style: CodeStyle,
+    /// the nesting depth of the declaration which is currently being emitted, for indenting it in the pretty style.
+    depth: usize,
 }
 impl CEmitter {
-    pub fn new() -> Self {
+    pub fn with_symbol_prefix(symbol_prefix: &str) -> Self {
         Self {
             code: String::new(),
+            symbol_prefix: symbol_prefix.to_string(),
+            table_linkage: TableLinkage::Default,
+            table_declarations: String::new(),
+            struct_layout: StructLayout::Packed,
+            layouts: Vec::new(),
+            table_stats: Vec::new(),
+            pending_fields: Vec::new(),
+            pending_values: Vec::new(),
+            code_style: CodeStyle::Compact,
+            depth: 0,
         }
     }
 
@@ -13,13 +122,343 @@ impl CEmitter {
         &self.code
     }
 
+    pub fn set_table_linkage(&mut self, table_linkage: TableLinkage) {
+        self.table_linkage = table_linkage;
+    }
+
+    pub fn table_declarations(&self) -> &str {
+        &self.table_declarations
+    }
+
+    pub fn set_struct_layout(&mut self, struct_layout: StructLayout) {
+        self.struct_layout = struct_layout;
+    }
+
+    pub fn set_code_style(&mut self, code_style: CodeStyle) {
+        self.code_style = code_style;
+    }
+
+    fn is_pretty(&self) -> bool {
+        self.code_style == CodeStyle::Pretty
+    }
+
+    /// the separator between the elements of an initializer list.
+    fn list_separator(&self) -> &'static str {
+        if self.is_pretty() {
+            ", "
+        } else {
+            ","
+        }
+    }
+
+    fn push_indent(&mut self) {
+        if self.is_pretty() {
+            for _ in 0..self.depth {
+                self.code.push_str("    ");
+            }
+        }
+    }
+
+    /// begins a struct or union declaration, or a struct embedded in one, given the line which opens it.
+    fn open_declaration(&mut self, opening_line: &str) {
+        self.push_indent();
+        self.code.push_str(opening_line);
+        self.code.push('\n');
+        self.depth += 1;
+    }
+
+    fn close_declaration(&mut self, name: &str) {
+        self.depth -= 1;
+        self.push_indent();
+        self.code.push('}');
+        if self.is_pretty() {
+            self.code.push(' ');
+        }
+        self.code.push_str(name);
+        self.code.push_str(";\n");
+    }
+
+    /// emits a member of a struct or union declaration.
+    fn member(&mut self, member: &str) {
+        self.push_indent();
+        self.code.push_str(member);
+        self.code.push('\n');
+    }
+
+    /// separates top level declarations in the pretty style.
+    fn end_top_level_declaration(&mut self) {
+        if self.is_pretty() {
+            self.code.push('\n');
+        }
+    }
+
+    /// makes the structs and unions declared by the other emitter available to this one, so that it can emit tables of
+    /// them.
+    pub fn import_types(&mut self, other: &CEmitter) {
+        self.layouts.extend(other.layouts.iter().cloned());
+    }
+
+    fn layout(&self, type_name: &str) -> &Layout {
+        self.layouts
+            .iter()
+            .find(|(name, _)| name == type_name)
+            .map(|(_, layout)| layout)
+            .unwrap_or_else(|| panic!("unknown type {}", type_name))
+    }
+
+    /// the size in bytes of the given integer type or declared type.
+    pub fn type_size(&self, type_name: &str) -> usize {
+        match int_type_bits(type_name) {
+            Some(bits) => bits / 8,
+            None => self.layout(type_name).size,
+        }
+    }
+
+    pub fn table_stats(&self) -> &[TableStats] {
+        &self.table_stats
+    }
+
+    /// the bit fields of all declared structs and unions, including the ones of their embedded structs.
+    pub fn bit_field_stats(&self) -> Vec<BitFieldStats> {
+        self.layouts
+            .iter()
+            .flat_map(|(type_name, layout)| {
+                layout.fields.iter().filter_map(|field| match field.kind {
+                    LayoutFieldKind::Bits(bits) => Some(BitFieldStats {
+                        type_name: type_name.clone(),
+                        path: field.path.clone(),
+                        bits,
+                    }),
+                    _ => None,
+                })
+            })
+            .collect()
+    }
+
+    fn field_bits(&self, kind: &LayoutFieldKind) -> usize {
+        match kind {
+            LayoutFieldKind::Bits(bits) | LayoutFieldKind::Int(bits) => *bits,
+            LayoutFieldKind::Struct(type_name) => self.layout(type_name).size * 8,
+            LayoutFieldKind::Array(type_name, len) => self.layout(type_name).size * 8 * len,
+        }
+    }
+
+    /// ends the declaration of a struct or union, and emits its accessors. in the portable layout, the declaration
+    /// itself is also only emitted here, once its size is known.
+    fn end_type(&mut self, type_name: String, accessor_prefix: &str) {
+        let fields = std::mem::take(&mut self.pending_fields);
+        let size = fields
+            .iter()
+            .map(|field| (field.offset + self.field_bits(&field.kind)).div_ceil(8))
+            .max()
+            .unwrap_or(0);
+        if self.struct_layout == StructLayout::Portable {
+            assert!(size > 0, "{} has no fields", type_name);
+            self.open_declaration("typedef struct {");
+            self.member(&format!("uint8_t bytes[{}];", size));
+            self.close_declaration(&type_name);
+        }
+        for field in &fields {
+            self.emit_accessor(accessor_prefix, field);
+        }
+        self.end_top_level_declaration();
+        self.layouts.push((type_name, Layout { size, fields }));
+    }
+
+    /// emits an accessor macro for the given field, which takes a pointer to the struct. fields of struct types are
+    /// accessed by pointer, and arrays additionally take an index.
+    fn emit_accessor(&mut self, accessor_prefix: &str, field: &LayoutField) {
+        let accessor_name = format!(
+            "{}_{}",
+            accessor_prefix,
+            field.path.replace('.', "_").to_uppercase()
+        );
+        let byte_offset = field.offset / 8;
+        let (params, value) = match (&field.kind, self.struct_layout) {
+            (LayoutFieldKind::Bits(_) | LayoutFieldKind::Int(_), StructLayout::Packed) => {
+                ("p", format!("((p)->{})", field.path))
+            }
+            (LayoutFieldKind::Struct(_), StructLayout::Packed) => {
+                ("p", format!("(&(p)->{})", field.path))
+            }
+            (LayoutFieldKind::Array(..), StructLayout::Packed) => {
+                ("p, i", format!("(&(p)->{}[(i)])", field.path))
+            }
+            (LayoutFieldKind::Bits(bits) | LayoutFieldKind::Int(bits), StructLayout::Portable) => {
+                ("p", gen_portable_bits_accessor(field.offset, *bits))
+            }
+            (LayoutFieldKind::Struct(type_name), StructLayout::Portable) => (
+                "p",
+                format!("((const {}*)&(p)->bytes[{}])", type_name, byte_offset),
+            ),
+            (LayoutFieldKind::Array(type_name, _), StructLayout::Portable) => (
+                "p, i",
+                format!(
+                    "((const {}*)&(p)->bytes[{} + (i) * {}])",
+                    type_name,
+                    byte_offset,
+                    self.layout(type_name).size
+                ),
+            ),
+        };
+        self.define(&format!("{}({})", accessor_name, params), &value);
+    }
+
+    /// collects the paths, offsets and sizes in bits of all integer fields of the given type, including the fields of
+    /// the structs which it contains.
+    fn collect_bits_fields(
+        &self,
+        type_name: &str,
+        path_prefix: &str,
+        base_offset: usize,
+        bits_fields: &mut Vec<(String, usize, usize)>,
+    ) {
+        for field in &self.layout(type_name).fields {
+            let path = format!("{}{}", path_prefix, field.path);
+            let offset = base_offset + field.offset;
+            match &field.kind {
+                LayoutFieldKind::Bits(bits) | LayoutFieldKind::Int(bits) => {
+                    bits_fields.push((path, offset, *bits))
+                }
+                LayoutFieldKind::Struct(field_type) => {
+                    self.collect_bits_fields(field_type, &format!("{}.", path), offset, bits_fields)
+                }
+                LayoutFieldKind::Array(element_type, len) => {
+                    let element_bits = self.layout(element_type).size * 8;
+                    for i in 0..*len {
+                        self.collect_bits_fields(
+                            element_type,
+                            &format!("{}[{}].", path, i),
+                            offset + i * element_bits,
+                            bits_fields,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// packs the given field values of a table entry into the bytes of its portable layout. values which aren't integer
+    /// literals, like enum variants, are packed using constant expressions.
+    fn pack_entry(&self, entry_type: &str, values: &[(String, String)]) -> String {
+        let mut bits_fields = Vec::new();
+        self.collect_bits_fields(entry_type, "", 0, &mut bits_fields);
+        // the constant part of each byte, and the expressions which are or'ed into it
+        let mut bytes: Vec<(u64, Vec<String>)> =
+            vec![(0, Vec::new()); self.layout(entry_type).size];
+        for (path, value) in values {
+            let &(_, offset, bits) = bits_fields
+                .iter()
+                .find(|(field_path, _, _)| field_path == path)
+                .unwrap_or_else(|| panic!("{} has no field {}", entry_type, path));
+            let field_bytes = bytes
+                .iter_mut()
+                .enumerate()
+                .take((offset + bits).div_ceil(8))
+                .skip(offset / 8);
+            for (byte_index, (constant, terms)) in field_bytes {
+                let start = max(offset, byte_index * 8);
+                let end = min(offset + bits, byte_index * 8 + 8);
+                let value_shift = start - offset;
+                let byte_shift = start - byte_index * 8;
+                let mask = (1u64 << (end - start)) - 1;
+                match value.parse::<u64>() {
+                    Ok(int_value) => {
+                        assert!(
+                            int_value >> bits == 0,
+                            "the value {} of {} doesn't fit in {} bits",
+                            int_value,
+                            path,
+                            bits
+                        );
+                        *constant |= ((int_value >> value_shift) & mask) << byte_shift;
+                    }
+                    Err(_) => {
+                        let mut term = format!("({})", value);
+                        if value_shift != 0 {
+                            term = format!("({} >> {})", term, value_shift);
+                        }
+                        term = format!("({} & {:#x})", term, mask);
+                        if byte_shift != 0 {
+                            term = format!("({} << {})", term, byte_shift);
+                        }
+                        terms.push(term);
+                    }
+                }
+            }
+        }
+        let bytes: Vec<String> = bytes
+            .into_iter()
+            .map(|(constant, mut terms)| {
+                if constant != 0 || terms.is_empty() {
+                    terms.push(format!("{:#04x}", constant));
+                }
+                terms.join("|")
+            })
+            .collect();
+        format!("{{{{{}}}}}", bytes.join(self.list_separator()))
+    }
+
+    /// the storage class and qualifiers of table definitions.
+    fn table_qualifiers(&self) -> &'static str {
+        match self.table_linkage {
+            TableLinkage::Static => "static const ",
+            TableLinkage::Default | TableLinkage::Extern => "const ",
+        }
+    }
+
+    /// records the stats of an emitted table, and declares it if its linkage is `TableLinkage::Extern`.
+    fn end_table(&mut self, element_type: &str, table_name: &str, len: usize, size: usize) {
+        self.table_stats.push(TableStats {
+            name: table_name.to_string(),
+            entries: len,
+            size,
+        });
+        if self.table_linkage == TableLinkage::Extern {
+            self.table_declarations.push_str(&format!(
+                "extern const {} {}[{}];\n",
+                element_type, table_name, len
+            ));
+        }
+    }
+
+    /// the name of the given symbol after applying the symbol prefix. the names of constants and macros are uppercase,
+    /// so they get an uppercase prefix.
+    pub fn symbol(&self, name: &str) -> String {
+        if name.chars().any(|c| c.is_ascii_lowercase()) {
+            format!("{}{}", self.symbol_prefix, name)
+        } else {
+            format!("{}{}", self.symbol_prefix.to_uppercase(), name)
+        }
+    }
+
     pub fn pragma_once(&mut self) {
         self.code.push_str("#pragma once\n");
     }
 
+    /// begins an `#ifndef` include guard. the guard must be closed using `end_ifndef_guard`.
+    pub fn begin_ifndef_guard(&mut self, guard_name: &str) {
+        let guard_name = self.symbol(guard_name);
+        self.code.push_str("#ifndef ");
+        self.code.push_str(&guard_name);
+        self.code.push('\n');
+        self.code.push_str("#define ");
+        self.code.push_str(&guard_name);
+        self.code.push('\n');
+    }
+
+    pub fn end_ifndef_guard(&mut self) {
+        self.code.push_str("#endif\n");
+    }
+
+    /// defines a macro. the name may include a parameter list, for function like macros.
     pub fn define(&mut self, define_name: &str, define_value: &str) {
+        let define_name = match define_name.split_once('(') {
+            Some((name, params)) => format!("{}({}", self.symbol(name), params),
+            None => self.symbol(define_name),
+        };
         self.code.push_str("#define ");
-        self.code.push_str(define_name);
+        self.code.push_str(&define_name);
         self.code.push(' ');
         self.code.push_str(define_value);
         self.code.push('\n');
@@ -27,41 +466,67 @@ impl CEmitter {
 
     pub fn begin_tagged_union(
         &mut self,
-        union_name: &'static str,
+        union_name: &str,
         kinds_amount: usize,
     ) -> CTaggedUnionEmitter {
-        self.code
-            .push_str("typedef union __attribute__((packed)) {\n");
-
+        let accessor_prefix = gen_accessor_prefix(union_name);
+        let union_name = self.symbol(union_name);
         let kind_field = gen_bit_field_min_size("kind", kinds_amount);
-        self.code.push_str(&kind_field);
+        if self.struct_layout == StructLayout::Packed {
+            self.open_declaration("typedef union __attribute__((packed)) {");
+            self.member(&kind_field);
+        }
+        let kind_bits = min_bits_required_for_field(kinds_amount);
+        self.pending_fields.push(LayoutField {
+            path: "kind".to_string(),
+            offset: 0,
+            kind: LayoutFieldKind::Bits(kind_bits),
+        });
 
         CTaggedUnionEmitter {
             emitter: self,
             union_name,
+            accessor_prefix,
             kind_field,
+            kind_bits,
         }
     }
 
-    pub fn begin_struct(&mut self, struct_name: &'static str) -> CStructEmitter {
-        self.code
-            .push_str("typedef struct __attribute__((packed)) {\n");
+    pub fn begin_struct(&mut self, struct_name: &str) -> CStructEmitter {
+        let accessor_prefix = gen_accessor_prefix(struct_name);
+        let struct_name = self.symbol(struct_name);
+        if self.struct_layout == StructLayout::Packed {
+            self.open_declaration("typedef struct __attribute__((packed)) {");
+        }
 
         CStructEmitter {
             emitter: self,
             struct_name,
+            accessor_prefix: Some(accessor_prefix),
+            path_prefix: String::new(),
+            offset: 0,
         }
     }
 
-    pub fn begin_union(&mut self, union_name: &'static str) -> CUnionEmitter {
-        self.code
-            .push_str("typedef union __attribute__((packed)) {\n");
+    pub fn begin_union(&mut self, union_name: &str) -> CUnionEmitter {
+        let accessor_prefix = gen_accessor_prefix(union_name);
+        let union_name = self.symbol(union_name);
+        if self.struct_layout == StructLayout::Packed {
+            self.open_declaration("typedef union __attribute__((packed)) {");
+        }
         CUnionEmitter {
             emitter: self,
             union_name,
+            accessor_prefix,
         }
     }
 
+    pub fn include_local(&mut self, header_file_name: &str) {
+        self.code.push_str("#include \"");
+        self.code.push_str(header_file_name);
+        self.code.push_str("\"\n");
+    }
+
     pub fn include_system(&mut self, header_file_name: &str) {
         self.code.push_str("#include <");
         self.code.push_str(header_file_name);
@@ -73,35 +538,122 @@ impl CEmitter {
         S: AsRef<str>,
         I: IntoIterator<Item = S>,
     {
-        self.code.push_str("typedef enum {");
-        let mut last_variant = None;
-        for variant in variants {
-            self.code.push_str(variant.as_ref());
-            self.code.push(',');
-            last_variant = Some(variant);
-        }
-        if let Some(last_variant) = last_variant {
-            self.code.push_str(enum_prefix);
-            self.code.push_str("MAX = ");
-            self.code.push_str(last_variant.as_ref());
-            self.code.push(',');
+        let mut variants: Vec<String> = variants
+            .into_iter()
+            .map(|variant| self.symbol(variant.as_ref()))
+            .collect();
+        if let Some(last_variant) = variants.last() {
+            let max_variant = self.symbol(&format!("{}MAX", enum_prefix));
+            variants.push(format!("{} = {}", max_variant, last_variant));
+        }
+        let enum_name = self.symbol(enum_name);
+        if self.is_pretty() {
+            self.open_declaration("typedef enum {");
+            for variant in &variants {
+                self.member(&format!("{},", variant));
+            }
+            self.close_declaration(&enum_name);
+            self.end_top_level_declaration();
+        } else {
+            self.code.push_str("typedef enum {");
+            for variant in &variants {
+                self.code.push_str(variant);
+                self.code.push(',');
+            }
+            self.code.push('}');
+            self.code.push_str(&enum_name);
+            self.code.push_str(";\n");
         }
-        self.code.push('}');
-        self.code.push_str(enum_name);
-        self.code.push_str(";\n");
     }
 
+    pub fn emit_string_table<S, I>(&mut self, table_name: &str, strings: I)
+    where
+        S: AsRef<str>,
+        I: IntoIterator<Item = S>,
+    {
+        let table_name = self.symbol(table_name);
+        self.code.push_str(self.table_qualifiers());
+        self.code.push_str("char* const ");
+        self.code.push_str(&table_name);
+        self.code.push_str("[] = {");
+        if self.is_pretty() {
+            self.code.push('\n');
+        }
+        let mut len = 0;
+        let mut strings_size = 0;
+        for string in strings {
+            strings_size += string.as_ref().len() + 1;
+            if self.is_pretty() {
+                self.code.push_str("    \"");
+                self.code.push_str(string.as_ref());
+                self.code.push_str("\",\n");
+            } else {
+                self.code.push('"');
+                self.code.push_str(string.as_ref());
+                self.code.push_str("\",");
+            }
+            len += 1;
+        }
+        self.code.push_str("};\n");
+        self.end_top_level_declaration();
+        self.end_table("char* const", &table_name, len, len * 8 + strings_size);
+    }
+
+    /// emits a table of bytes, in which bit `i % 8` of byte `i / 8` is the `i`th bit.
+    pub fn emit_bitmap<I>(&mut self, table_name: &str, bits: I)
+    where
+        I: IntoIterator<Item = bool>,
+    {
+        let bits: Vec<bool> = bits.into_iter().collect();
+        let mut table = self.begin_table("uint8_t", table_name);
+        for (byte_index, byte_bits) in bits.chunks(8).enumerate() {
+            table.int_entry(
+                byte_bits
+                    .iter()
+                    .enumerate()
+                    .map(|(i, bit)| (*bit as usize) << i)
+                    .sum(),
+            );
+            table.comment(&format!(
+                "bits {:#04x}-{:#04x}",
+                byte_index * 8,
+                byte_index * 8 + byte_bits.len() - 1
+            ));
+        }
+        table.emit();
+    }
+
+    /// emits handwritten code as is.
+    pub fn raw(&mut self, code: &str) {
+        self.code.push_str(code);
+    }
+
+    /// begins a table of the given element type. the element type is not prefixed, since it may be a builtin type, so
+    /// types declared by the emitter must be passed through `symbol`.
     pub fn begin_table(&mut self, struct_name: &str, table_name: &str) -> CTableEmitter {
-        self.code.push_str("const ");
+        let table_name = self.symbol(table_name);
+        self.code.push_str(self.table_qualifiers());
         self.code.push_str(struct_name);
         self.code.push(' ');
-        self.code.push_str(table_name);
+        self.code.push_str(&table_name);
         self.code.push_str("[] = {");
-        CTableEmitter { emitter: self }
+        if self.is_pretty() {
+            self.code.push('\n');
+        }
+        CTableEmitter {
+            emitter: self,
+            struct_name: struct_name.to_string(),
+            table_name,
+            rows: Vec::new(),
+        }
     }
 }
 
 pub fn min_bits_required_for_field(values_amount: usize) -> usize {
+    if values_amount <= 2 {
+        // a bit field must be at least 1 bit wide, even if it only has a single possible value
+        return 1;
+    }
     // round up log2
     (values_amount - 1).ilog2() as usize + 1
 }
@@ -121,79 +673,191 @@ pub fn min_int_type_required_for_field(values_amount: usize) -> &'static str {
 pub fn gen_bit_field_min_size(field_name: &str, values_amount: usize) -> String {
     let int_type = min_int_type_required_for_field(values_amount);
     let bits_required = min_bits_required_for_field(values_amount);
-    format!("{int_type} {field_name}: {bits_required};\n")
+    format!("{int_type} {field_name}: {bits_required};")
+}
+
+/// the prefix of the accessor macros of the given type, for example `OP_INFO` for `op_info_t`.
+fn gen_accessor_prefix(type_name: &str) -> String {
+    type_name
+        .strip_suffix("_t")
+        .unwrap_or(type_name)
+        .to_uppercase()
+}
+
+/// the size in bits of the given integer type, if it is one.
+fn int_type_bits(type_name: &str) -> Option<usize> {
+    match type_name {
+        "uint8_t" => Some(8),
+        "uint16_t" => Some(16),
+        "uint32_t" => Some(32),
+        "uint64_t" => Some(64),
+        _ => None,
+    }
+}
+
+/// an expression which extracts the given bits from the bytes of a struct in the portable layout.
+fn gen_portable_bits_accessor(offset: usize, bits: usize) -> String {
+    let first_byte = offset / 8;
+    let shift = offset % 8;
+    assert!(
+        shift + bits <= 32,
+        "fields wider than 25 bits aren't supported in the portable layout"
+    );
+    let word: Vec<String> = (0..(shift + bits).div_ceil(8))
+        .map(|i| {
+            if i == 0 {
+                format!("(uint32_t)(p)->bytes[{}]", first_byte)
+            } else {
+                format!("(uint32_t)(p)->bytes[{}] << {}", first_byte + i, i * 8)
+            }
+        })
+        .collect();
+    let mut value = format!("({})", word.join(" | "));
+    if shift != 0 {
+        value = format!("({} >> {})", value, shift);
+    }
+    format!("({} & {:#x})", value, (1u64 << bits) - 1)
 }
 
 pub struct CTaggedUnionEmitter<'a> {
     emitter: &'a mut CEmitter,
-    union_name: &'static str,
+    union_name: String,
+    accessor_prefix: String,
     kind_field: String,
+    kind_bits: usize,
 }
 impl<'a> CTaggedUnionEmitter<'a> {
-    pub fn begin_struct_variant(&mut self, variant_name: &'static str) -> CStructEmitter {
-        self.emitter
-            .code
-            .push_str("struct __attribute__((packed)) {\n");
-        self.emitter.code.push_str(&self.kind_field);
-        CStructEmitter {
-            emitter: self.emitter,
-            struct_name: variant_name,
+    pub fn begin_struct_variant(&mut self, variant_name: &str) -> CStructEmitter {
+        if self.emitter.struct_layout == StructLayout::Packed {
+            self.emitter
+                .open_declaration("struct __attribute__((packed)) {");
+            self.emitter.member(&self.kind_field);
         }
+        let mut variant = CStructEmitter {
+            emitter: self.emitter,
+            struct_name: variant_name.to_string(),
+            accessor_prefix: None,
+            path_prefix: format!("{}.", variant_name),
+            offset: 0,
+        };
+        variant.add_field("kind", LayoutFieldKind::Bits(self.kind_bits), false);
+        variant
     }
 
     pub fn emit(self) {
-        self.emitter.code.push('}');
-        self.emitter.code.push_str(self.union_name);
-        self.emitter.code.push_str(";\n");
+        if self.emitter.struct_layout == StructLayout::Packed {
+            self.emitter.close_declaration(&self.union_name);
+        }
+        self.emitter
+            .end_type(self.union_name, &self.accessor_prefix);
     }
 }
 
 pub struct CUnionEmitter<'a> {
     emitter: &'a mut CEmitter,
-    union_name: &'static str,
+    union_name: String,
+    accessor_prefix: String,
 }
 impl<'a> CUnionEmitter<'a> {
-    pub fn begin_embedded_struct(&mut self, field_name: &'static str) -> CStructEmitter {
-        self.emitter
-            .code
-            .push_str("struct __attribute__((packed)) {\n");
+    pub fn begin_embedded_struct(&mut self, field_name: &str) -> CStructEmitter {
+        if self.emitter.struct_layout == StructLayout::Packed {
+            self.emitter
+                .open_declaration("struct __attribute__((packed)) {");
+        }
         CStructEmitter {
             emitter: self.emitter,
-            struct_name: field_name,
+            struct_name: field_name.to_string(),
+            accessor_prefix: None,
+            path_prefix: format!("{}.", field_name),
+            offset: 0,
         }
     }
 
     pub fn field(&mut self, field_type: &str, field_name: &str) -> &mut Self {
-        self.emitter.code.push_str(field_type);
-        self.emitter.code.push(' ');
-        self.emitter.code.push_str(field_name);
-        self.emitter.code.push(';');
+        if self.emitter.struct_layout == StructLayout::Packed {
+            self.emitter
+                .member(&format!("{} {};", field_type, field_name));
+        }
+        self.emitter.pending_fields.push(LayoutField {
+            path: field_name.to_string(),
+            offset: 0,
+            kind: field_type_layout_kind(field_type),
+        });
         self
     }
 
     pub fn bit_field(&mut self, field_name: &str, values_amount: usize) -> &mut Self {
-        self.emitter
-            .code
-            .push_str(&gen_bit_field_min_size(field_name, values_amount));
+        if self.emitter.struct_layout == StructLayout::Packed {
+            self.emitter
+                .member(&gen_bit_field_min_size(field_name, values_amount));
+        }
+        self.emitter.pending_fields.push(LayoutField {
+            path: field_name.to_string(),
+            offset: 0,
+            kind: LayoutFieldKind::Bits(min_bits_required_for_field(values_amount)),
+        });
         self
     }
 
     pub fn emit(&mut self) {
-        self.emitter.code.push('}');
-        self.emitter.code.push_str(self.union_name);
-        self.emitter.code.push_str(";\n");
+        if self.emitter.struct_layout == StructLayout::Packed {
+            self.emitter.close_declaration(&self.union_name);
+        }
+        self.emitter
+            .end_type(self.union_name.clone(), &self.accessor_prefix);
+    }
+}
+
+/// the layout kind of a field of the given type, which is either an integer type or a type declared by the emitter.
+fn field_type_layout_kind(field_type: &str) -> LayoutFieldKind {
+    match int_type_bits(field_type) {
+        Some(bits) => LayoutFieldKind::Int(bits),
+        None => LayoutFieldKind::Struct(field_type.to_string()),
     }
 }
 
 pub struct CStructEmitter<'a> {
     emitter: &'a mut CEmitter,
-    struct_name: &'static str,
+    struct_name: String,
+    /// the prefix of the accessors of the struct, unless it is embedded in another declaration.
+    accessor_prefix: Option<String>,
+    /// the prefix of the paths of the fields, which is the name of the struct followed by a `.` if it is embedded in
+    /// another declaration.
+    path_prefix: String,
+    /// the offset in bits of the next field.
+    offset: usize,
 }
 impl<'a> CStructEmitter<'a> {
+    fn add_field(&mut self, field_name: &str, kind: LayoutFieldKind, byte_aligned: bool) {
+        if byte_aligned {
+            self.offset = self.offset.next_multiple_of(8);
+        }
+        let bits = self.emitter.field_bits(&kind);
+        self.emitter.pending_fields.push(LayoutField {
+            path: format!("{}{}", self.path_prefix, field_name),
+            offset: self.offset,
+            kind,
+        });
+        self.offset += bits;
+    }
+    pub fn field(&mut self, field_type: &str, field_name: &str) -> &mut Self {
+        if self.emitter.struct_layout == StructLayout::Packed {
+            self.emitter
+                .member(&format!("{} {};", field_type, field_name));
+        }
+        self.add_field(field_name, field_type_layout_kind(field_type), true);
+        self
+    }
     pub fn bit_field(&mut self, field_name: &str, values_amount: usize) -> &mut Self {
-        self.emitter
-            .code
-            .push_str(&gen_bit_field_min_size(field_name, values_amount));
+        if self.emitter.struct_layout == StructLayout::Packed {
+            self.emitter
+                .member(&gen_bit_field_min_size(field_name, values_amount));
+        }
+        self.add_field(
+            field_name,
+            LayoutFieldKind::Bits(min_bits_required_for_field(values_amount)),
+            false,
+        );
         self
     }
     pub fn array_field(
@@ -202,93 +866,339 @@ impl<'a> CStructEmitter<'a> {
         field_name: &str,
         array_size: usize,
     ) -> &mut Self {
-        self.emitter.code.push_str(field_type);
-        self.emitter.code.push(' ');
-        self.emitter.code.push_str(field_name);
-        self.emitter.code.push('[');
-        self.emitter.code.push_str(&array_size.to_string());
-        self.emitter.code.push_str("];");
+        if self.emitter.struct_layout == StructLayout::Packed {
+            self.emitter
+                .member(&format!("{} {}[{}];", field_type, field_name, array_size));
+        }
+        self.add_field(
+            field_name,
+            LayoutFieldKind::Array(field_type.to_string(), array_size),
+            true,
+        );
         self
     }
     pub fn emit(&mut self) {
-        self.emitter.code.push('}');
-        self.emitter.code.push_str(self.struct_name);
-        self.emitter.code.push_str(";\n");
+        if self.emitter.struct_layout == StructLayout::Packed {
+            self.emitter.close_declaration(&self.struct_name);
+        }
+        if let Some(accessor_prefix) = &self.accessor_prefix {
+            self.emitter
+                .end_type(self.struct_name.clone(), accessor_prefix);
+        }
     }
 }
 
 pub struct CTableEmitter<'a> {
     emitter: &'a mut CEmitter,
+    struct_name: String,
+    table_name: String,
+    /// the offset in the code of each entry, along with its comment, so that the comments can be aligned once all of
+    /// the entries are emitted.
+    rows: Vec<(usize, Option<String>)>,
 }
 impl<'a> CTableEmitter<'a> {
     pub fn begin_entry(&mut self) -> CStructValueEmitter {
-        self.emitter.code.push('{');
+        self.rows.push((self.emitter.code.len(), None));
+        if self.emitter.struct_layout == StructLayout::Packed {
+            self.emitter.code.push('{');
+        }
         CStructValueEmitter {
             emitter: self.emitter,
+            entry_type: Some(self.struct_name.clone()),
+            path_prefix: String::new(),
+            items_amount: 0,
         }
     }
     pub fn int_entry(&mut self, value: usize) {
+        self.rows.push((self.emitter.code.len(), None));
         self.emitter.code.push_str(&value.to_string());
-        self.emitter.code.push_str(",\n");
+        if !self.emitter.is_pretty() {
+            self.emitter.code.push_str(",\n");
+        }
+    }
+    /// describes the last entry in a comment, which is only emitted in the pretty style.
+    pub fn comment(&mut self, comment: &str) {
+        self.rows.last_mut().unwrap().1 = Some(comment.to_string());
     }
     pub fn emit(self) {
+        if self.emitter.is_pretty() && !self.rows.is_empty() {
+            // each entry is emitted on a single line, so the lines are laid out once all of them are known
+            let entries_start = self.rows[0].0;
+            let entries_code = self.emitter.code.split_off(entries_start);
+            let starts: Vec<usize> = self
+                .rows
+                .iter()
+                .map(|(start, _)| start - entries_start)
+                .collect();
+            let ends = starts.iter().skip(1).copied().chain([entries_code.len()]);
+            let lines: Vec<String> = starts
+                .iter()
+                .zip(ends)
+                .map(|(start, end)| format!("    {},", &entries_code[*start..end]))
+                .collect();
+            let comment_column = lines
+                .iter()
+                .zip(&self.rows)
+                .filter(|(_, (_, comment))| comment.is_some())
+                .map(|(line, _)| line.len())
+                .max()
+                .unwrap_or(0);
+            for (line, (_, comment)) in lines.iter().zip(&self.rows) {
+                self.emitter.code.push_str(line);
+                if let Some(comment) = comment {
+                    self.emitter
+                        .code
+                        .push_str(&" ".repeat(comment_column - line.len()));
+                    self.emitter.code.push_str(" // ");
+                    self.emitter.code.push_str(comment);
+                }
+                self.emitter.code.push('\n');
+            }
+        }
         self.emitter.code.push_str("};\n");
+        self.emitter.end_top_level_declaration();
+        let size = self.rows.len() * self.emitter.type_size(&self.struct_name);
+        self.emitter
+            .end_table(&self.struct_name, &self.table_name, self.rows.len(), size);
     }
 }
 
 pub struct CStructValueEmitter<'a> {
     emitter: &'a mut CEmitter,
+    /// the type of the table entry, if this is a whole entry rather than a struct nested in one.
+    entry_type: Option<String>,
+    /// the prefix of the paths of the fields within the table entry, which are used to collect the values of the entry
+    /// in the portable layout.
+    path_prefix: String,
+    /// the amount of fields emitted so far, for separating them in the pretty style.
+    items_amount: usize,
 }
 impl<'a> CStructValueEmitter<'a> {
-    pub fn field(self, field_name: &str, value: &str) -> Self {
+    fn begin_field(&mut self, field_name: &str) {
+        if self.emitter.is_pretty() && self.items_amount != 0 {
+            self.emitter.code.push_str(", ");
+        }
+        self.items_amount += 1;
         self.emitter.code.push('.');
         self.emitter.code.push_str(field_name);
-        self.emitter.code.push('=');
+        if self.emitter.is_pretty() {
+            self.emitter.code.push_str(" = ");
+        } else {
+            self.emitter.code.push('=');
+        }
+    }
+    pub fn field(mut self, field_name: &str, value: &str) -> Self {
+        if self.emitter.struct_layout == StructLayout::Portable {
+            self.emitter.pending_values.push((
+                format!("{}{}", self.path_prefix, field_name),
+                value.to_string(),
+            ));
+            return self;
+        }
+        self.begin_field(field_name);
         self.emitter.code.push_str(value);
-        self.emitter.code.push(',');
+        if !self.emitter.is_pretty() {
+            self.emitter.code.push(',');
+        }
         self
     }
+    /// a field whose value is a symbol declared by the emitter, like an enum variant.
+    pub fn field_symbol(self, field_name: &str, symbol: &str) -> Self {
+        let value = self.emitter.symbol(symbol);
+        self.field(field_name, &value)
+    }
     pub fn field_int(self, field_name: &str, value: usize) -> Self {
-        self.emitter.code.push('.');
-        self.emitter.code.push_str(field_name);
-        self.emitter.code.push('=');
-        self.emitter.code.push_str(&value.to_string());
-        self.emitter.code.push(',');
-        self
+        self.field(field_name, &value.to_string())
     }
     pub fn begin_struct_field(&mut self, field_name: &str) -> CStructValueEmitter {
-        self.emitter.code.push('.');
-        self.emitter.code.push_str(field_name);
-        self.emitter.code.push_str("={");
+        if self.emitter.struct_layout == StructLayout::Packed {
+            self.begin_field(field_name);
+            self.emitter.code.push('{');
+        }
         CStructValueEmitter {
             emitter: self.emitter,
+            entry_type: None,
+            path_prefix: format!("{}{}.", self.path_prefix, field_name),
+            items_amount: 0,
         }
     }
     pub fn begin_array_field(&mut self, field_name: &str) -> CArrayValueEmitter {
-        self.emitter.code.push('.');
-        self.emitter.code.push_str(field_name);
-        self.emitter.code.push_str("={");
+        if self.emitter.struct_layout == StructLayout::Packed {
+            self.begin_field(field_name);
+            self.emitter.code.push('{');
+        }
         CArrayValueEmitter {
             emitter: self.emitter,
+            path: format!("{}{}", self.path_prefix, field_name),
+            len: 0,
         }
     }
     pub fn emit(self) {
-        self.emitter.code.push_str("},\n");
+        match (self.emitter.struct_layout, &self.entry_type) {
+            (StructLayout::Packed, _) => {
+                if self.emitter.is_pretty() {
+                    self.emitter.code.push('}');
+                } else {
+                    self.emitter.code.push_str("},\n");
+                }
+            }
+            (StructLayout::Portable, Some(entry_type)) => {
+                let values = std::mem::take(&mut self.emitter.pending_values);
+                let entry = self.emitter.pack_entry(entry_type, &values);
+                self.emitter.code.push_str(&entry);
+                if !self.emitter.is_pretty() {
+                    self.emitter.code.push_str(",\n");
+                }
+            }
+            (StructLayout::Portable, None) => {}
+        }
     }
 }
 
 pub struct CArrayValueEmitter<'a> {
     emitter: &'a mut CEmitter,
+    /// the path of the array within the table entry.
+    path: String,
+    len: usize,
 }
 impl<'a> CArrayValueEmitter<'a> {
     pub fn begin_struct_element(&mut self) -> CStructValueEmitter {
-        self.emitter.code.push('{');
+        if self.emitter.struct_layout == StructLayout::Packed {
+            if self.emitter.is_pretty() && self.len != 0 {
+                self.emitter.code.push_str(", ");
+            }
+            self.emitter.code.push('{');
+        }
+        let path_prefix = format!("{}[{}].", self.path, self.len);
+        self.len += 1;
 
         CStructValueEmitter {
             emitter: self.emitter,
+            entry_type: None,
+            path_prefix,
+            items_amount: 0,
         }
     }
     pub fn emit(self) {
-        self.emitter.code.push_str("},\n");
+        if self.emitter.struct_layout == StructLayout::Packed {
+            if self.emitter.is_pretty() {
+                self.emitter.code.push('}');
+            } else {
+                self.emitter.code.push_str("},\n");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        c_test_utils::{compile_and_run, compile_and_run_sources},
+        generate_code, CCodeArgs, IncludeGuard, TablesMode,
+    };
+
+    use super::*;
+
+    /// two copies which are generated with different symbol prefixes must be usable in a single program.
+    #[test]
+    fn prefixed_copies_dont_collide() {
+        let prefixed = CCodeArgs {
+            symbol_prefix: "x86_32_".to_string(),
+            types_file_name: "x86_32_types.h".to_string(),
+            tables_file_name: "x86_32_tables.h".to_string(),
+            include_guard: IncludeGuard::Ifndef,
+            ..CCodeArgs::default()
+        };
+        let program = r#"#include <stdio.h>
+#include "types.h"
+#include "tables.h"
+#include "x86_32_types.h"
+#include "x86_32_tables.h"
+#include "x86_32_types.h"
+int main(void) {
+    printf("%s %s\n", mnemonic_names[MNEMONIC_ADD], x86_32_mnemonic_names[X86_32_MNEMONIC_ADD]);
+    printf("%zu\n", sizeof(x86_32_insn_info_t) * X86_32_X86_TABLES_INSN_MAX_OPS);
+    return 0;
+}
+"#;
+        let output = compile_and_run(
+            &[CCodeArgs::default(), prefixed],
+            "prefixed_copies",
+            program,
+        );
+        assert!(output.starts_with("add add\n"), "{}", output);
+    }
+
+    /// the tables header must be includable from multiple translation units of a single program in the given mode.
+    fn check_tables_header_links_into_multiple_translation_units(
+        name: &str,
+        tables_mode: TablesMode,
+    ) {
+        let args = CCodeArgs {
+            tables_mode,
+            ..CCodeArgs::default()
+        };
+        let other = r#"#include "types.h"
+#include "tables.h"
+const char* other_mnemonic_name(void) {
+    return mnemonic_names[MNEMONIC_ADD];
+}
+"#;
+        let main = r#"#include <stdio.h>
+#include "types.h"
+#include "tables.h"
+const char* other_mnemonic_name(void);
+int main(void) {
+    printf("%s %s\n", mnemonic_names[MNEMONIC_ADD], other_mnemonic_name());
+    return 0;
+}
+"#;
+        let output =
+            compile_and_run_sources(&[args], name, &[], &[("main", main), ("other", other)]);
+        assert_eq!(output, "add add\n");
+    }
+
+    #[test]
+    fn static_tables_link_into_multiple_translation_units() {
+        check_tables_header_links_into_multiple_translation_units(
+            "static_multiple_units",
+            TablesMode::Static,
+        );
+    }
+
+    #[test]
+    fn source_tables_link_into_multiple_translation_units() {
+        check_tables_header_links_into_multiple_translation_units(
+            "source_multiple_units",
+            TablesMode::Source,
+        );
+    }
+
+    /// in the pretty style, every entry of the opcode byte tables is annotated with the instruction which it encodes.
+    #[test]
+    fn pretty_opcode_byte_tables_are_annotated() {
+        let args = CCodeArgs {
+            code_style: CodeStyle::Pretty,
+            ..CCodeArgs::default()
+        };
+        let tables = generate_code(&args).unwrap().tables_file.code().to_string();
+        let first_opcode_byte_table = tables
+            .split("first_opcode_byte_table[] = {\n")
+            .nth(1)
+            .unwrap();
+        let first_entry = first_opcode_byte_table.lines().next().unwrap();
+        assert!(
+            first_entry.starts_with("    {.regular = {.mnemonic = MNEMONIC_ADD, ")
+                && first_entry.ends_with(" // 0x00: add rm8, reg8"),
+            "{}",
+            first_entry
+        );
+        assert_eq!(
+            first_opcode_byte_table
+                .lines()
+                .take_while(|line| *line != "};")
+                .count(),
+            0x100
+        );
     }
 }
@@ -0,0 +1,82 @@
+//! helpers for tests which compile c programs against the generated headers.
+
+use std::{
+    process::Command,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::{generate_code, CCodeArgs};
+
+/// the tests of the crate run in parallel in a single process, so each call gets its own directory.
+static NEXT_DIR_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// compiles the given c program, which may include the headers generated with each of the given options, with the
+/// system c compiler, which can be overridden using the `CC` environment variable. runs it, and returns its output.
+pub fn compile_and_run(c_code_args: &[CCodeArgs], name: &str, program: &str) -> String {
+    compile_and_run_sources(c_code_args, name, &[], &[(name, program)])
+}
+
+/// like `compile_and_run`, but links a program made of multiple translation units, given by their names and sources,
+/// which may include the given additional headers, given by their file names and contents. generated tables source
+/// files are linked into the program as well.
+pub fn compile_and_run_sources(
+    c_code_args: &[CCodeArgs],
+    name: &str,
+    headers: &[(&str, &str)],
+    sources: &[(&str, &str)],
+) -> String {
+    let dir = std::env::temp_dir().join(format!(
+        "x86_tables_{}_{}_{}",
+        name,
+        std::process::id(),
+        NEXT_DIR_ID.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    let mut source_paths = Vec::new();
+    for args in c_code_args {
+        let generated_code = generate_code(args).unwrap();
+        std::fs::write(
+            dir.join(&args.types_file_name),
+            generated_code.types_file.code(),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join(&args.tables_file_name),
+            generated_code.tables_file.code(),
+        )
+        .unwrap();
+        if let Some(tables_source_file) = &generated_code.tables_source_file {
+            let path = dir.join(&args.tables_source_file_name);
+            std::fs::write(&path, tables_source_file.code()).unwrap();
+            source_paths.push(path);
+        }
+    }
+    for (header_name, header) in headers {
+        std::fs::write(dir.join(header_name), header).unwrap();
+    }
+    for (source_name, source) in sources {
+        let path = dir.join(format!("{}.c", source_name));
+        std::fs::write(&path, source).unwrap();
+        source_paths.push(path);
+    }
+
+    let cc = std::env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let exe = dir.join(name);
+    let output = Command::new(&cc)
+        .arg("-o")
+        .arg(&exe)
+        .args(&source_paths)
+        .output()
+        .unwrap_or_else(|err| panic!("failed to run the c compiler `{}`: {}", cc, err));
+    assert!(
+        output.status.success(),
+        "{} failed to compile:\n{}",
+        name,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = Command::new(&exe).output().unwrap();
+    assert!(output.status.success(), "{} failed", exe.display());
+    std::fs::remove_dir_all(&dir).unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
@@ -0,0 +1,187 @@
+// decodes the encodings of `encodings.h` through the emitted tables, and prints the result of each of them, one line
+// per encoding. the format must match the one of `differential_tests.rs`. the fields are only accessed through the
+// generated accessors, so that the harness works with every struct layout.
+#include <stdio.h>
+
+#include "types.h"
+#include "tables.h"
+
+// the size of the encodings, which must match the one of `differential_tests.rs`.
+#define ENCODING_LEN 24
+
+typedef struct {
+    uint8_t is_64_bit;
+    uint8_t operand_size_override;
+    uint8_t address_size_override;
+    uint8_t rex_w;
+    uint8_t lock;
+    uint8_t rep;
+    uint8_t prefixes_len;
+    uint8_t code[ENCODING_LEN];
+} encoding_t;
+
+#include "encodings.h"
+
+static unsigned op_size_bytes(unsigned op_size_info_index, const encoding_t* encoding) {
+    const op_size_info_t* op_size_info = &op_size_infos_table[op_size_info_index];
+    unsigned op_size;
+    if (encoding->rex_w) {
+        op_size = OP_SIZE_INFO_MODE_64_WITH_REX_W(op_size_info);
+    } else if (encoding->operand_size_override) {
+        op_size = OP_SIZE_INFO_WITH_OPERAND_SIZE_OVERRIDE(op_size_info);
+    } else if (encoding->is_64_bit) {
+        op_size = OP_SIZE_INFO_MODE_64(op_size_info);
+    } else {
+        op_size = OP_SIZE_INFO_MODE_32(op_size_info);
+    }
+    return 1u << op_size;
+}
+
+static unsigned mem_offset_size(const encoding_t* encoding) {
+    unsigned addr_size = encoding->is_64_bit ? 8 : 4;
+    return encoding->address_size_override ? addr_size / 2 : addr_size;
+}
+
+// the size of the immediates of an instruction, computed from its operands.
+static unsigned insn_imm_size(const regular_insn_info_t* insn, const encoding_t* encoding) {
+    unsigned size = 0;
+    for (unsigned i = 0; i < REGULAR_INSN_INFO_OPS_AMOUNT(insn); i++) {
+        const op_info_t* op = &op_infos_table[laid_out_ops_infos_table[REGULAR_INSN_INFO_FIRST_OP_INDEX(insn) + i]];
+        switch (OP_INFO_KIND(op)) {
+        case OP_KIND_IMM:
+            size += op_size_bytes(OP_INFO_IMM_ENCODED_SIZE_INFO_INDEX(op), encoding);
+            break;
+        case OP_KIND_REL:
+            // near branches ignore the operand size override prefix in 64-bit mode
+            if (encoding->is_64_bit) {
+                size += 1u << OP_SIZE_INFO_MODE_64(&op_size_infos_table[OP_INFO_REL_SIZE_INFO_INDEX(op)]);
+            } else {
+                size += op_size_bytes(OP_INFO_REL_SIZE_INFO_INDEX(op), encoding);
+            }
+            break;
+        case OP_KIND_MEM_OFFSET:
+            size += mem_offset_size(encoding);
+            break;
+        default:
+            break;
+        }
+    }
+    return size;
+}
+
+// the size of the immediates of an opcode, looked up in the immediate size table of its opcode map.
+static unsigned opcode_imm_size(const imm_size_info_t* imm_size_info, const encoding_t* encoding) {
+    unsigned size;
+    if (encoding->rex_w) {
+        size = IMM_SIZE_INFO_MODE_64_WITH_REX_W(imm_size_info);
+    } else if (encoding->is_64_bit && encoding->operand_size_override) {
+        size = IMM_SIZE_INFO_MODE_64_WITH_OPERAND_SIZE_OVERRIDE(imm_size_info);
+    } else if (encoding->operand_size_override) {
+        size = IMM_SIZE_INFO_WITH_OPERAND_SIZE_OVERRIDE(imm_size_info);
+    } else if (encoding->is_64_bit) {
+        size = IMM_SIZE_INFO_MODE_64(imm_size_info);
+    } else {
+        size = IMM_SIZE_INFO_MODE_32(imm_size_info);
+    }
+    if (IMM_SIZE_INFO_HAS_MEM_OFFSET(imm_size_info)) {
+        size += mem_offset_size(encoding);
+    }
+    return size;
+}
+
+static void decode(const encoding_t* encoding) {
+    const uint8_t* code = &encoding->code[encoding->prefixes_len];
+    unsigned len = encoding->prefixes_len;
+    const insn_info_t* table = first_opcode_byte_table;
+    const uint8_t* supported_table = first_opcode_byte_supported;
+    const uint8_t* has_modrm_table = first_opcode_byte_has_modrm;
+    const imm_size_info_t* imm_size_table = first_opcode_byte_imm_size_by_mode;
+    if (*code == 0x0f) {
+        table = second_opcode_byte_table;
+        supported_table = second_opcode_byte_supported;
+        has_modrm_table = second_opcode_byte_has_modrm;
+        imm_size_table = second_opcode_byte_imm_size_by_mode;
+        code++;
+        len++;
+    }
+    uint8_t opcode = *code;
+    code++;
+    len++;
+    if (!X86_TABLES_IS_SUPPORTED(supported_table, opcode)) {
+        printf("unsupported\n");
+        return;
+    }
+
+    const insn_info_t* insn_info = &table[opcode];
+    const regular_insn_info_t* insn = INSN_INFO_REGULAR(insn_info);
+    int rm_is_memory = 0;
+    if (X86_TABLES_HAS_MODRM(has_modrm_table, opcode)) {
+        uint8_t modrm = code[0];
+        uint8_t mod = modrm >> 6;
+        uint8_t rm = modrm & 7;
+        len++;
+        rm_is_memory = mod != 3;
+        if (!encoding->is_64_bit && encoding->address_size_override) {
+            // 16-bit addressing, which has no sib byte
+            if ((mod == 0 && rm == 6) || mod == 2) {
+                len += 2;
+            } else if (mod == 1) {
+                len += 1;
+            }
+        } else {
+            if (mod != 3 && rm == 4) {
+                len++;
+                if (mod == 0 && (code[1] & 7) == 5) {
+                    len += 4;
+                }
+            }
+            if ((mod == 0 && rm == 5) || mod == 2) {
+                len += 4;
+            } else if (mod == 1) {
+                len += 1;
+            }
+        }
+
+        if (INSN_INFO_MNEMONIC(insn_info) == MNEMONIC_MODRM_REG_OPCODE_EXT) {
+            const modrm_reg_opcode_ext_table_t* modrm_reg_table =
+                &modrm_reg_opcode_ext_tables[INSN_INFO_MODRM_REG_OPCODE_EXT_MODRM_REG_TABLE_INDEX(insn_info)];
+            insn = MODRM_REG_OPCODE_EXT_TABLE_BY_REG_VALUE(modrm_reg_table, (modrm >> 3) & 7);
+        } else if (INSN_INFO_MNEMONIC(insn_info) == MNEMONIC_REP_PREFIX_MODRM_EXT) {
+            const rep_prefix_modrm_ext_table_t* rep_prefix_modrm_table =
+                &rep_prefix_modrm_ext_tables[INSN_INFO_REP_PREFIX_MODRM_EXT_REP_PREFIX_MODRM_TABLE_INDEX(insn_info)];
+            insn = REP_PREFIX_MODRM_EXT_TABLE_DEFAULT_INSN(rep_prefix_modrm_table);
+            for (unsigned i = 0; encoding->rep && i < REP_PREFIX_MODRM_EXT_TABLE_OVERRIDES_AMOUNT(rep_prefix_modrm_table);
+                 i++) {
+                const rep_prefix_modrm_override_t* rep_prefix_override =
+                    &rep_prefix_modrm_overrides_table[REP_PREFIX_MODRM_EXT_TABLE_FIRST_OVERRIDE_INDEX(rep_prefix_modrm_table) + i];
+                if (REP_PREFIX_MODRM_OVERRIDE_MODRM(rep_prefix_override) == modrm) {
+                    insn = REP_PREFIX_MODRM_OVERRIDE_INSN(rep_prefix_override);
+                    break;
+                }
+            }
+        }
+    }
+
+    if (REGULAR_INSN_INFO_MNEMONIC(insn) == MNEMONIC_UNSUPPORTED) {
+        printf("unsupported\n");
+        return;
+    }
+    if (encoding->lock && !(REGULAR_INSN_INFO_LOCKABLE(insn) && rm_is_memory)) {
+        printf("invalid\n");
+        return;
+    }
+    const imm_size_info_t* imm_size_info = &imm_size_table[opcode];
+    if (IMM_SIZE_INFO_DEPENDS_ON_MODRM(imm_size_info)) {
+        len += insn_imm_size(insn, encoding);
+    } else {
+        len += opcode_imm_size(imm_size_info, encoding);
+    }
+    printf("%s %u\n", mnemonic_names[REGULAR_INSN_INFO_MNEMONIC(insn)], len);
+}
+
+int main(void) {
+    for (size_t i = 0; i < sizeof(encodings) / sizeof(encodings[0]); i++) {
+        decode(&encodings[i]);
+    }
+    return 0;
+}
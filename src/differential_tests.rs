@@ -0,0 +1,866 @@
+//! differential testing of the tables against the iced-x86 decoder.
+//!
+//! every opcode of every map is decoded in both 32-bit and 64-bit mode with random prefixes and random trailing bytes,
+//! both by a minimal decoder which walks the table model, and by iced-x86. the mnemonic, the length and the operand
+//! sizes are compared, and all disagreements are reported per opcode table entry. encodings which the model rejects
+//! must be rejected by the reference as well.
+//!
+//! a c harness decodes random encodings the same way through the emitted tables, and its results are compared with the
+//! ones of the model, so that the emitted tables are checked against the reference through the model.
+//!
+//! the generated length decoder is tested the same way, with arbitrary sequences of prefixes, including multiple and
+//! misplaced rex prefixes.
+
+use std::collections::BTreeMap;
+
+use iced_x86::{Decoder, DecoderOptions, Formatter, Instruction, IntelFormatter, OpKind};
+
+use crate::{
+    c_test_utils::compile_and_run_sources,
+    first_opcode_byte_table::gen_first_opcode_byte_table,
+    imm_size_info::ImmSizeInfo,
+    insn_defs::{MAP_0F, MAP_PRIMARY},
+    length_decoder::gen_length_decoder,
+    second_opcode_byte_table::gen_second_opcode_byte_table,
+    table_types::*,
+    CCodeArgs,
+};
+
+/// the amount of random encodings which are decoded for each opcode in each mode.
+const ENCODINGS_PER_OPCODE: usize = 200;
+
+/// the amount of random encodings which are decoded through the emitted tables for each opcode in each mode. the
+/// encodings are compiled into the test program, so there are less of them.
+const EMITTED_TABLES_ENCODINGS_PER_OPCODE: usize = 24;
+
+/// the size of the encodings which are decoded through the emitted tables, which must match the one of
+/// `differential_harness.c`.
+const HARNESS_ENCODING_LEN: usize = 24;
+
+const DIFFERENTIAL_HARNESS_C: &str = include_str!("differential_harness.c");
+
+/// the amount of random encodings which are decoded by the generated length decoder for each opcode in each mode. the
+/// encodings are compiled into the test program, so there are less of them.
+const LENGTH_DECODER_ENCODINGS_PER_OPCODE: usize = 32;
+
+/// the size of each encoding which is decoded by the generated length decoder: up to 4 prefixes, an escape byte, the
+/// opcode, and the longest possible tail.
+const LENGTH_DECODER_ENCODING_LEN: usize = 22;
+
+/// disagreements which are caused by known limitations of the model, by their location. the test fails if any of these
+/// no longer occurs, so that this list doesn't outlive the limitations.
+fn known_disagreements() -> Vec<(String, &'static str)> {
+    const LEA_WITH_REG: &str = "the model doesn't reject `lea` with a register operand";
+    const UD_OP_SIZE: &str =
+        "the reference applies the operand size to the operands of `ud0` and `ud1`, which the model doesn't describe";
+    const NEAR_BRANCH_OP_SIZE: &str =
+        "the reference ignores the operand size override prefix of indirect near branches in 64-bit mode";
+    let mut known = Vec::new();
+    for bitness in [32, 64] {
+        known.push((format!("{}-bit primary 0x8d", bitness), LEA_WITH_REG));
+        known.push((format!("{}-bit 0f 0xb9", bitness), UD_OP_SIZE));
+        known.push((format!("{}-bit 0f 0xff", bitness), UD_OP_SIZE));
+    }
+    for location in ["primary 0xff /2", "primary 0xff /4"] {
+        known.push((format!("64-bit {}", location), NEAR_BRANCH_OP_SIZE));
+    }
+    known
+}
+
+/// a small deterministic xorshift generator, so that failures are reproducible.
+struct Rng(u64);
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn next_u8(&mut self) -> u8 {
+        self.next_u64() as u8
+    }
+
+    fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.next_u64() as usize % items.len()]
+    }
+}
+
+/// whether the given opcode exists in the given mode. the tables describe both modes, so opcodes which are only valid in
+/// one of them have the meaning of the other mode, which can't be compared.
+fn is_opcode_valid_in_mode(map: &str, opcode: u8, bitness: u32) -> bool {
+    match (bitness, map) {
+        // these are the rex prefixes and `into`
+        (64, MAP_PRIMARY) => !matches!(opcode, 0x40..=0x4f | 0xce),
+        // this is `arpl`, which was replaced by `movsxd`
+        (32, MAP_PRIMARY) => opcode != 0x63,
+        _ => true,
+    }
+}
+
+#[derive(Default)]
+struct Prefixes {
+    operand_size_override: bool,
+    address_size_override: bool,
+    rex_w: bool,
+    lock: bool,
+    rep: bool,
+}
+
+/// the result of decoding an encoding by walking the table model.
+enum ModelDecoding {
+    /// the tables don't support the instruction.
+    Unsupported,
+    /// the instruction is supported, but the encoding is invalid, for example because of a misplaced LOCK prefix.
+    Invalid,
+    Valid(ModelInsn),
+}
+
+struct ModelInsn {
+    insn: RegularInsnInfo,
+    len: usize,
+    rm_is_memory: bool,
+}
+
+fn model_op_size(size: &OpSizeInfo, prefixes: &Prefixes, bitness: u32) -> OpSize {
+    if prefixes.rex_w {
+        size.mode_64_with_rex_w
+    } else if prefixes.operand_size_override {
+        size.with_operand_size_override
+    } else if bitness == 64 {
+        size.mode_64
+    } else {
+        size.mode_32
+    }
+}
+
+/// decodes the given encoding, which starts right after the prefixes, using the table model.
+fn model_decode(
+    map: &str,
+    table: &[InsnInfo],
+    prefixes: &Prefixes,
+    bitness: u32,
+    prefixes_len: usize,
+    code: &[u8],
+) -> ModelDecoding {
+    let escape_len = if map == MAP_0F { 1 } else { 0 };
+    let opcode = code[escape_len];
+    let insn_info = &table[opcode as usize];
+    let mut len = prefixes_len + escape_len + 1;
+    let mut rm_is_memory = false;
+    let insn = if insn_info.has_modrm() {
+        let modrm = code[escape_len + 1];
+        let mod_ = modrm >> 6;
+        let rm = modrm & 7;
+        len += 1;
+        rm_is_memory = mod_ != 3;
+        if bitness == 32 && prefixes.address_size_override {
+            // 16-bit addressing, which has no sib byte
+            if (mod_ == 0 && rm == 6) || mod_ == 2 {
+                len += 2;
+            } else if mod_ == 1 {
+                len += 1;
+            }
+        } else {
+            if mod_ != 3 && rm == 4 {
+                let sib = code[escape_len + 2];
+                len += 1;
+                if mod_ == 0 && sib & 7 == 5 {
+                    len += 4;
+                }
+            }
+            if (mod_ == 0 && rm == 5) || mod_ == 2 {
+                len += 4;
+            } else if mod_ == 1 {
+                len += 1;
+            }
+        }
+        match insn_info {
+            InsnInfo::Regular(insn) => insn.clone(),
+            InsnInfo::ModrmRegOpcodeExt(modrm_reg_opcode_ext) => {
+                modrm_reg_opcode_ext.by_reg_value[((modrm >> 3) & 7) as usize].clone()
+            }
+            InsnInfo::RepPrefixModrmExt(rep_prefix_modrm_ext) => rep_prefix_modrm_ext
+                .overrides
+                .iter()
+                .find(|x| prefixes.rep && x.modrm == modrm)
+                .map(|x| x.insn.clone())
+                .unwrap_or_else(|| rep_prefix_modrm_ext.default.clone()),
+        }
+    } else {
+        let InsnInfo::Regular(insn) = insn_info else {
+            unreachable!()
+        };
+        insn.clone()
+    };
+    if insn.is_unsupported() {
+        return ModelDecoding::Unsupported;
+    }
+    if prefixes.lock && !(insn.lockable && rm_is_memory) {
+        return ModelDecoding::Invalid;
+    }
+    let imm_size_info = ImmSizeInfo::of_insn(&insn);
+    len += match () {
+        _ if prefixes.rex_w => imm_size_info.mode_64_with_rex_w,
+        _ if bitness == 64 && prefixes.operand_size_override => {
+            imm_size_info.mode_64_with_operand_size_override
+        }
+        _ if prefixes.operand_size_override => imm_size_info.with_operand_size_override,
+        _ if bitness == 64 => imm_size_info.mode_64,
+        _ => imm_size_info.mode_32,
+    };
+    if imm_size_info.has_mem_offset {
+        let addr_size = if bitness == 64 { 8 } else { 4 };
+        len += if prefixes.address_size_override {
+            addr_size / 2
+        } else {
+            addr_size
+        };
+    }
+    ModelDecoding::Valid(ModelInsn {
+        insn,
+        len,
+        rm_is_memory,
+    })
+}
+
+/// the string instructions, whose operands are all implicit in the model.
+const STRING_MNEMONICS: &[&str] = &["movs", "cmps", "scas", "lods", "stos", "ins", "outs"];
+
+/// whether the mnemonic of the model matches the one of the reference, which has separate mnemonics for the
+/// different conditions and operand sizes of some instructions.
+fn mnemonic_matches(insn: &RegularInsnInfo, reference_mnemonic: &str) -> bool {
+    if insn.ops.contains(&OpInfo::Cond) {
+        let family = insn.mnemonic.strip_suffix("cc").unwrap();
+        return reference_mnemonic.starts_with(family);
+    }
+    if reference_mnemonic == insn.mnemonic {
+        return true;
+    }
+    match insn.mnemonic {
+        _ if STRING_MNEMONICS.contains(&insn.mnemonic) => {
+            reference_mnemonic.len() == insn.mnemonic.len() + 1
+                && reference_mnemonic.starts_with(insn.mnemonic)
+                && reference_mnemonic.ends_with(['b', 'w', 'd', 'q'])
+        }
+        "cwd" => matches!(reference_mnemonic, "cdq" | "cqo"),
+        "sysret" => reference_mnemonic == "sysretq",
+        "sysexit" => reference_mnemonic == "sysexitq",
+        "movsx" => matches!(reference_mnemonic, "cbw" | "cwde" | "cdqe"),
+        "iret" => matches!(reference_mnemonic, "iretd" | "iretq"),
+        "jrcxz" => matches!(reference_mnemonic, "jcxz" | "jecxz"),
+        // the reference decodes the reserved nop space according to the newest extensions which use it
+        _ => is_hint_nop(insn.mnemonic) && is_hint_nop(reference_mnemonic),
+    }
+}
+
+fn is_hint_nop(mnemonic: &str) -> bool {
+    matches!(mnemonic, "nop" | "reservednop" | "cldemote")
+        || mnemonic.starts_with("prefetch")
+        || mnemonic.starts_with("rdssp")
+}
+
+/// the kinds and the sizes in bits of the operands of the reference which correspond to the explicit operands of the
+/// model.
+fn reference_op_sizes(reference: &Instruction) -> Vec<(OpKind, Option<usize>)> {
+    (0..reference.op_count())
+        .map(|i| {
+            let op_kind = reference.op_kind(i);
+            let size = match op_kind {
+                OpKind::Register => Some(reference.op_register(i).size() * 8),
+                OpKind::Memory => Some(reference.memory_size().size() * 8),
+                OpKind::Immediate8 | OpKind::Immediate8_2nd => Some(8),
+                OpKind::Immediate16 | OpKind::Immediate8to16 => Some(16),
+                OpKind::Immediate32 | OpKind::Immediate8to32 => Some(32),
+                OpKind::Immediate64 | OpKind::Immediate8to64 | OpKind::Immediate32to64 => Some(64),
+                // the size of branch targets is the size of the instruction pointer, which the model doesn't describe
+                _ => None,
+            };
+            (op_kind, size)
+        })
+        .collect()
+}
+
+/// whether the size of the given reference operand is its encoded size rather than the size it is extended to.
+fn is_encoded_size_imm(op_kind: OpKind) -> bool {
+    matches!(
+        op_kind,
+        OpKind::Immediate8 | OpKind::Immediate8_2nd | OpKind::Immediate16 | OpKind::Immediate32
+    )
+}
+
+/// the sizes in bits of an operand of the model.
+#[derive(Clone, Copy)]
+struct ModelOpSize {
+    size: usize,
+    /// the encoded size of immediates, which is the size of the operand for all other operands.
+    encoded_size: usize,
+}
+
+/// the sizes in bits of the operands of the model which the reference shows explicitly. specific registers are
+/// explicit in some instructions, like `add al, 1`, and implicit in others, like `cpuid`, so they are only included
+/// if the operand amounts match that way.
+fn model_op_sizes(
+    model: &ModelInsn,
+    prefixes: &Prefixes,
+    bitness: u32,
+    reference_op_count: usize,
+) -> Vec<Option<ModelOpSize>> {
+    let sized = |size: &OpSizeInfo| {
+        let size = model_op_size(size, prefixes, bitness) as usize;
+        Some(Some(ModelOpSize {
+            size,
+            encoded_size: size,
+        }))
+    };
+    let op_size = |op: &OpInfo, include_specific_regs: bool| match op {
+        OpInfo::Imm(imm) => Some(Some(ModelOpSize {
+            size: model_op_size(&imm.extended_size, prefixes, bitness) as usize,
+            encoded_size: model_op_size(&imm.encoded_size, prefixes, bitness) as usize,
+        })),
+        // specific immediates, like the 1 of `shl eax, 1`, are not encoded, so their size is irrelevant
+        OpInfo::SpecificImm(_) => Some(None),
+        OpInfo::Reg(reg) => sized(&reg.size),
+        OpInfo::Rm(size) => sized(size),
+        OpInfo::SpecificReg(reg) if include_specific_regs => sized(&reg.size),
+        OpInfo::ZextSpecificReg(reg) if include_specific_regs => sized(&reg.size),
+        OpInfo::MemOffset(mem_offset) => sized(&mem_offset.mem_operand_size),
+        OpInfo::Rel(_) => Some(None),
+        _ => None,
+    };
+    let with_specific_regs: Vec<Option<ModelOpSize>> = model
+        .insn
+        .ops
+        .iter()
+        .filter_map(|op| op_size(op, true))
+        .collect();
+    if with_specific_regs.len() == reference_op_count {
+        with_specific_regs
+    } else {
+        model
+            .insn
+            .ops
+            .iter()
+            .filter_map(|op| op_size(op, false))
+            .collect()
+    }
+}
+
+fn compare(
+    model: &ModelDecoding,
+    prefixes: &Prefixes,
+    bitness: u32,
+    reference: &Instruction,
+) -> Result<(), String> {
+    let model = match model {
+        // the tables are incomplete by design, so instructions which they don't support are not compared
+        ModelDecoding::Unsupported => return Ok(()),
+        ModelDecoding::Invalid if reference.is_invalid() => return Ok(()),
+        ModelDecoding::Invalid => {
+            let reference_mnemonic = format!("{:?}", reference.mnemonic()).to_lowercase();
+            return Err(format!(
+                "the model rejects the encoding, but the reference decodes `{}`",
+                reference_mnemonic
+            ));
+        }
+        ModelDecoding::Valid(model) => model,
+    };
+    if reference.is_invalid() {
+        return Err(format!(
+            "the model decodes `{}`, but the reference rejects the encoding",
+            model.insn.mnemonic
+        ));
+    }
+    let reference_mnemonic = format!("{:?}", reference.mnemonic()).to_lowercase();
+    if !mnemonic_matches(&model.insn, &reference_mnemonic) {
+        return Err(format!(
+            "mnemonic `{}` differs from `{}`",
+            model.insn.mnemonic, reference_mnemonic
+        ));
+    }
+    if model.len != reference.len() {
+        return Err(format!(
+            "length {} differs from {}",
+            model.len,
+            reference.len()
+        ));
+    }
+    // the operands of string instructions are implicit in the model, and the ones of hint nops are ignored, and
+    // described by the reference according to the extensions which use them
+    if STRING_MNEMONICS.contains(&model.insn.mnemonic) || is_hint_nop(model.insn.mnemonic) {
+        return Ok(());
+    }
+    let reference_op_sizes = reference_op_sizes(reference);
+    let model_op_sizes = model_op_sizes(model, prefixes, bitness, reference_op_sizes.len());
+    if model_op_sizes.len() != reference_op_sizes.len() {
+        return Err(format!(
+            "{} operands differ from {} operands",
+            model_op_sizes.len(),
+            reference_op_sizes.len()
+        ));
+    }
+    for (i, (model_size, (reference_op_kind, reference_size))) in
+        model_op_sizes.iter().zip(&reference_op_sizes).enumerate()
+    {
+        if let (Some(model_size), Some(reference_size)) = (model_size, reference_size) {
+            let model_size = if is_encoded_size_imm(*reference_op_kind) {
+                model_size.encoded_size
+            } else {
+                model_size.size
+            };
+            // memory operands without a size, like the one of `lea`, have a size of 0 in the reference
+            let is_unsized_memory =
+                *reference_op_kind == OpKind::Memory && *reference_size == 0 && model.rm_is_memory;
+            if model_size != *reference_size && !is_unsized_memory {
+                return Err(format!(
+                    "operand {} size {} differs from {}",
+                    i, model_size, reference_size
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// a disagreement between the model and the reference for a single opcode table entry.
+struct Disagreement {
+    message: String,
+    example: Vec<u8>,
+    reference_text: String,
+    amount: usize,
+}
+
+fn location(
+    bitness: u32,
+    map: &str,
+    opcode: u8,
+    insn_info: &InsnInfo,
+    code: &[u8],
+    escape_len: usize,
+) -> String {
+    let location = format!("{}-bit {} {:#04x}", bitness, map, opcode);
+    let modrm = code[escape_len + 1];
+    match insn_info {
+        InsnInfo::Regular(_) => location,
+        InsnInfo::ModrmRegOpcodeExt(_) => format!("{} /{}", location, (modrm >> 3) & 7),
+        InsnInfo::RepPrefixModrmExt(rep_prefix_modrm_ext) => {
+            match rep_prefix_modrm_ext
+                .overrides
+                .iter()
+                .find(|x| x.modrm == modrm)
+            {
+                Some(_) => format!("{} with modrm {:#04x}", location, modrm),
+                None => location,
+            }
+        }
+    }
+}
+
+/// whether the rep prefix selects or modifies any instruction of the given opcode table entry.
+fn uses_rep_prefix(insn_info: &InsnInfo) -> bool {
+    match insn_info {
+        InsnInfo::RepPrefixModrmExt(_) => true,
+        _ => table_regular_insns(insn_info).any(|insn| insn.rep_kind != RepKind::None),
+    }
+}
+
+/// a random encoding of an opcode, made of its prefixes, the opcode and the random bytes which follow it.
+struct Encoding {
+    prefixes: Prefixes,
+    prefixes_len: usize,
+    code: Vec<u8>,
+}
+
+fn random_encoding(
+    rng: &mut Rng,
+    bitness: u32,
+    insn_info: &InsnInfo,
+    escape: &[u8],
+    opcode: u8,
+) -> Encoding {
+    // only prefixes whose meaning the model describes are used, since other prefixes may select other instructions
+    // which the model doesn't know about, for example `F3 0F B8` is `popcnt`.
+    let prefixes = Prefixes {
+        operand_size_override: *rng.choose(&[false, false, true]),
+        address_size_override: *rng.choose(&[false, false, false, true]),
+        rex_w: bitness == 64 && *rng.choose(&[false, false, true]),
+        lock: *rng.choose(&[false, false, false, true]),
+        rep: uses_rep_prefix(insn_info) && *rng.choose(&[false, true]),
+    };
+    let mut code = Vec::new();
+    if prefixes.lock {
+        code.push(0xf0);
+    }
+    if prefixes.rep {
+        code.push(0xf3);
+    }
+    if prefixes.operand_size_override {
+        code.push(0x66);
+    }
+    if prefixes.address_size_override {
+        code.push(0x67);
+    }
+    if prefixes.rex_w {
+        code.push(0x48);
+    }
+    let prefixes_len = code.len();
+    code.extend_from_slice(escape);
+    code.push(opcode);
+    // the longest possible tail is a modrm, a sib, a displacement and two immediates
+    code.extend((0..16).map(|_| rng.next_u8()));
+    Encoding {
+        prefixes,
+        prefixes_len,
+        code,
+    }
+}
+
+#[test]
+fn tables_match_reference_decoder() {
+    let first_opcode_byte_table = gen_first_opcode_byte_table().unwrap();
+    let second_opcode_byte_table = gen_second_opcode_byte_table().unwrap();
+    let mut rng = Rng(0x2545f4914f6cdd1d);
+    let mut disagreements: BTreeMap<String, Disagreement> = BTreeMap::new();
+    let mut formatter = IntelFormatter::new();
+
+    for bitness in [32, 64] {
+        for (map, table) in [
+            (MAP_PRIMARY, &first_opcode_byte_table),
+            (MAP_0F, &second_opcode_byte_table),
+        ] {
+            let escape: &[u8] = if map == MAP_0F { &[0x0f] } else { &[] };
+            for opcode in 0..=0xffu8 {
+                if !is_opcode_valid_in_mode(map, opcode, bitness) {
+                    continue;
+                }
+                let insn_info = &table[opcode as usize];
+                for _ in 0..ENCODINGS_PER_OPCODE {
+                    let Encoding {
+                        prefixes,
+                        prefixes_len,
+                        code,
+                    } = random_encoding(&mut rng, bitness, insn_info, escape, opcode);
+                    let model = model_decode(
+                        map,
+                        table,
+                        &prefixes,
+                        bitness,
+                        prefixes_len,
+                        &code[prefixes_len..],
+                    );
+                    let mut decoder = Decoder::with_ip(bitness, &code, 0, DecoderOptions::NONE);
+                    let reference = decoder.decode();
+                    if let Err(message) = compare(&model, &prefixes, bitness, &reference) {
+                        let location = location(
+                            bitness,
+                            map,
+                            opcode,
+                            insn_info,
+                            &code[prefixes_len..],
+                            escape.len(),
+                        );
+                        let disagreement = disagreements.entry(location).or_insert_with(|| {
+                            let mut reference_text = String::new();
+                            formatter.format(&reference, &mut reference_text);
+                            Disagreement {
+                                message,
+                                example: code[..reference.len().max(1)].to_vec(),
+                                reference_text,
+                                amount: 0,
+                            }
+                        });
+                        disagreement.amount += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    let known_disagreements = known_disagreements();
+    let is_known = |location: &str| {
+        known_disagreements
+            .iter()
+            .any(|(known_location, _)| *known_location == location)
+    };
+    for (location, disagreement) in &disagreements {
+        println!(
+            "{}{}: {} ({} times), for example {:02x?}, which the reference decodes as `{}`",
+            if is_known(location) { "known: " } else { "" },
+            location,
+            disagreement.message,
+            disagreement.amount,
+            disagreement.example,
+            disagreement.reference_text
+        );
+    }
+    let unexpected = disagreements
+        .keys()
+        .filter(|location| !is_known(location))
+        .count();
+    assert_eq!(
+        unexpected, 0,
+        "{} opcode table entries disagree with the reference decoder",
+        unexpected
+    );
+    for (known_location, reason) in &known_disagreements {
+        assert!(
+            disagreements.contains_key(known_location),
+            "the known disagreement of {} ({}) no longer occurs",
+            known_location,
+            reason
+        );
+    }
+}
+
+fn table_regular_insns(insn_info: &InsnInfo) -> impl Iterator<Item = &RegularInsnInfo> {
+    match insn_info {
+        InsnInfo::Regular(insn) => std::slice::from_ref(insn).iter(),
+        InsnInfo::ModrmRegOpcodeExt(modrm_reg_opcode_ext) => {
+            modrm_reg_opcode_ext.by_reg_value.iter()
+        }
+        InsnInfo::RepPrefixModrmExt(rep_prefix_modrm_ext) => {
+            std::slice::from_ref(&rep_prefix_modrm_ext.default).iter()
+        }
+    }
+}
+
+/// an encoding which is decoded by the generated length decoder.
+struct LengthDecoderEncoding {
+    bitness: u32,
+    code: Vec<u8>,
+    location: String,
+}
+
+#[test]
+fn length_decoder_matches_reference_decoder() {
+    let first_opcode_byte_table = gen_first_opcode_byte_table().unwrap();
+    let second_opcode_byte_table = gen_second_opcode_byte_table().unwrap();
+    let mut rng = Rng(0x9e3779b97f4a7c15);
+
+    let mut encodings = Vec::new();
+    for bitness in [32, 64] {
+        for (map, table) in [
+            (MAP_PRIMARY, &first_opcode_byte_table),
+            (MAP_0F, &second_opcode_byte_table),
+        ] {
+            let escape: &[u8] = if map == MAP_0F { &[0x0f] } else { &[] };
+            for opcode in 0..=0xffu8 {
+                if !is_opcode_valid_in_mode(map, opcode, bitness) {
+                    continue;
+                }
+                let insn_info = &table[opcode as usize];
+                // the length decoder rejects unsupported opcodes anyway, and decodes the ones which are actually
+                // prefixes or escape bytes as such, so that their encodings would be attributed to the wrong entry.
+                if matches!(insn_info, InsnInfo::Regular(insn) if insn.is_unsupported()) {
+                    continue;
+                }
+                // like in the test of the model, only prefixes whose meaning the model describes are used, along with
+                // segment prefixes, which never affect the length.
+                let mut prefixes = vec![0x66, 0x67, 0xf0, 0x2e, 0x64];
+                if uses_rep_prefix(insn_info) {
+                    prefixes.push(0xf3);
+                }
+                if bitness == 64 {
+                    prefixes.extend(0x40..=0x4f);
+                }
+                for _ in 0..LENGTH_DECODER_ENCODINGS_PER_OPCODE {
+                    let prefixes_len = (rng.next_u64() % 5) as usize;
+                    let mut code: Vec<u8> =
+                        (0..prefixes_len).map(|_| *rng.choose(&prefixes)).collect();
+                    code.extend_from_slice(escape);
+                    code.push(opcode);
+                    code.resize_with(LENGTH_DECODER_ENCODING_LEN, || rng.next_u8());
+                    let location = location(
+                        bitness,
+                        map,
+                        opcode,
+                        insn_info,
+                        &code[prefixes_len..],
+                        escape.len(),
+                    );
+                    encodings.push(LengthDecoderEncoding {
+                        bitness,
+                        code,
+                        location,
+                    });
+                }
+            }
+        }
+    }
+
+    let mut program = String::from(
+        "#include <stdio.h>\n#include \"length_decoder.h\"\nstatic const uint8_t encodings[][LENGTH_DECODER_ENCODING_LEN] = {\n",
+    );
+    for encoding in &encodings {
+        let bytes: Vec<String> = encoding.code.iter().map(u8::to_string).collect();
+        program.push_str(&format!("{{{}}},\n", bytes.join(",")));
+    }
+    program.push_str("};\nstatic const uint8_t is_64_bit[] = {");
+    for encoding in &encodings {
+        program.push_str(if encoding.bitness == 64 { "1," } else { "0," });
+    }
+    program.push_str(
+        r#"};
+int main(void) {
+    for (size_t i = 0; i < sizeof(encodings) / sizeof(encodings[0]); i++) {
+        printf("%zu\n", length_decoder_insn_len(encodings[i], LENGTH_DECODER_ENCODING_LEN, is_64_bit[i]));
+    }
+    return 0;
+}
+"#,
+    );
+    let program = program.replace(
+        "LENGTH_DECODER_ENCODING_LEN",
+        &LENGTH_DECODER_ENCODING_LEN.to_string(),
+    );
+    let length_decoder = gen_length_decoder(
+        &first_opcode_byte_table,
+        &second_opcode_byte_table,
+        &CCodeArgs::default(),
+    );
+    let output = compile_and_run_sources(
+        &[],
+        "length_decoder_harness",
+        &[("length_decoder.h", length_decoder.code())],
+        &[("length_decoder_harness", &program)],
+    );
+
+    // the length decoder rejects unsupported instructions, and doesn't validate prefixes, so only encodings which both
+    // decoders accept are compared.
+    let mut compared = 0;
+    let mut mismatches: BTreeMap<String, String> = BTreeMap::new();
+    for (encoding, len) in encodings.iter().zip(output.lines()) {
+        let len: usize = len.parse().unwrap();
+        let reference =
+            Decoder::with_ip(encoding.bitness, &encoding.code, 0, DecoderOptions::NONE).decode();
+        if len == 0 || reference.is_invalid() {
+            continue;
+        }
+        compared += 1;
+        if len != reference.len() {
+            mismatches
+                .entry(encoding.location.clone())
+                .or_insert_with(|| {
+                    format!(
+                        "length {} differs from {}, for example {:02x?}",
+                        len,
+                        reference.len(),
+                        &encoding.code[..reference.len()]
+                    )
+                });
+        }
+    }
+    assert_eq!(output.lines().count(), encodings.len());
+    for (location, message) in &mismatches {
+        println!("{}: {}", location, message);
+    }
+    assert!(
+        mismatches.is_empty(),
+        "{} opcode table entries have lengths which differ from the reference decoder, out of {} compared encodings",
+        mismatches.len(),
+        compared
+    );
+}
+
+/// the result of decoding an encoding in the format of `differential_harness.c`.
+fn dump_model_decoding(model: &ModelDecoding) -> String {
+    match model {
+        ModelDecoding::Unsupported => "unsupported".to_string(),
+        ModelDecoding::Invalid => "invalid".to_string(),
+        ModelDecoding::Valid(model) => format!("{} {}", model.insn.mnemonic, model.len),
+    }
+}
+
+#[test]
+fn emitted_tables_decode_like_the_model() {
+    let first_opcode_byte_table = gen_first_opcode_byte_table().unwrap();
+    let second_opcode_byte_table = gen_second_opcode_byte_table().unwrap();
+    let mut rng = Rng(0xd1b54a32d192ed03);
+
+    let mut encodings_h = String::from("static const encoding_t encodings[] = {\n");
+    let mut expected = Vec::new();
+    for bitness in [32, 64] {
+        for (map, table) in [
+            (MAP_PRIMARY, &first_opcode_byte_table),
+            (MAP_0F, &second_opcode_byte_table),
+        ] {
+            let escape: &[u8] = if map == MAP_0F { &[0x0f] } else { &[] };
+            for opcode in 0..=0xffu8 {
+                if !is_opcode_valid_in_mode(map, opcode, bitness) {
+                    continue;
+                }
+                // the harness treats the primary 0x0f opcode as the escape byte of the second map.
+                if map == MAP_PRIMARY && opcode == 0x0f {
+                    continue;
+                }
+                let insn_info = &table[opcode as usize];
+                for _ in 0..EMITTED_TABLES_ENCODINGS_PER_OPCODE {
+                    let Encoding {
+                        prefixes,
+                        prefixes_len,
+                        mut code,
+                    } = random_encoding(&mut rng, bitness, insn_info, escape, opcode);
+                    let model = model_decode(
+                        map,
+                        table,
+                        &prefixes,
+                        bitness,
+                        prefixes_len,
+                        &code[prefixes_len..],
+                    );
+                    let location = location(
+                        bitness,
+                        map,
+                        opcode,
+                        insn_info,
+                        &code[prefixes_len..],
+                        escape.len(),
+                    );
+                    expected.push((location, dump_model_decoding(&model), code.clone()));
+
+                    code.resize(HARNESS_ENCODING_LEN, 0);
+                    let bytes: Vec<String> = code.iter().map(u8::to_string).collect();
+                    encodings_h.push_str(&format!(
+                        "{{{},{},{},{},{},{},{},{{{}}}}},\n",
+                        (bitness == 64) as u8,
+                        prefixes.operand_size_override as u8,
+                        prefixes.address_size_override as u8,
+                        prefixes.rex_w as u8,
+                        prefixes.lock as u8,
+                        prefixes.rep as u8,
+                        prefixes_len,
+                        bytes.join(",")
+                    ));
+                }
+            }
+        }
+    }
+    encodings_h.push_str("};\n");
+
+    let output = compile_and_run_sources(
+        &[CCodeArgs::default()],
+        "differential_harness",
+        &[("encodings.h", &encodings_h)],
+        &[("differential_harness", DIFFERENTIAL_HARNESS_C)],
+    );
+    assert_eq!(output.lines().count(), expected.len());
+    let mut mismatches: BTreeMap<String, String> = BTreeMap::new();
+    for ((location, expected_line, code), line) in expected.iter().zip(output.lines()) {
+        if line != expected_line {
+            mismatches.entry(location.clone()).or_insert_with(|| {
+                format!(
+                    "the emitted tables decode `{}`, but the model decodes `{}`, for example {:02x?}",
+                    line, expected_line, code
+                )
+            });
+        }
+    }
+    for (location, message) in &mismatches {
+        println!("{}: {}", location, message);
+    }
+    assert!(
+        mismatches.is_empty(),
+        "{} opcode table entries are decoded differently through the emitted tables",
+        mismatches.len()
+    );
+}
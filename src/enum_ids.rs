@@ -0,0 +1,224 @@
+//! stable values for the variants of the emitted enums which are collected from the tables, like `mnemonic_t`.
+//!
+//! by default, variants are numbered in the order in which they first appear in the tables, so adding a single
+//! instruction may renumber every variant after it, which breaks any data which stores these values. to prevent this,
+//! the values can be assigned by a stable key, and checked against an id registry file, which lists one variant name
+//! per line, where the line number, starting from 0, is the value of the variant.
+
+use std::collections::{HashMap, HashSet};
+
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum EnumOrder {
+    /// variants are numbered in the order in which they first appear in the tables
+    Appearance,
+    /// variants are numbered in alphabetical order
+    Alphabetical,
+    /// variants keep the values listed in the id registry, and new variants are appended to it in alphabetical order.
+    /// variants which were removed from the tables keep their values, so that they are never reused.
+    Registry,
+}
+
+/// whether the given name can be used as the name of a variant, which is lowercase since it is uppercased in the
+/// emitted enum.
+fn is_valid_variant_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// the variant names listed in the given registry, which must be unique and valid variant names.
+fn parse_registry<'a>(enum_name: &str, registry: &'a str) -> Result<Vec<&'a str>, String> {
+    let mut registered_names = HashSet::new();
+    registry
+        .lines()
+        .enumerate()
+        .map(|(line_index, name)| {
+            let line = line_index + 1;
+            if name.is_empty() {
+                return Err(format!(
+                    "line {} of the {} registry is empty",
+                    line, enum_name
+                ));
+            }
+            if !is_valid_variant_name(name) {
+                return Err(format!(
+                    "line {} of the {} registry, `{}`, is not a valid variant name",
+                    line, enum_name, name
+                ));
+            }
+            if !registered_names.insert(name) {
+                return Err(format!(
+                    "line {} of the {} registry, `{}`, is already listed in an earlier line",
+                    line, enum_name, name
+                ));
+            }
+            Ok(name)
+        })
+        .collect()
+}
+
+/// orders the given unique variant names, so that the index of each name is its value. if a registry is given, fails
+/// if it is malformed, or if the value of any variant listed in it would change. returns the ordered names, which are
+/// also the new contents of the registry.
+pub fn assign_enum_ids(
+    enum_name: &str,
+    names: &[&str],
+    order: EnumOrder,
+    registry: Option<&str>,
+) -> Result<Vec<String>, String> {
+    let registry_names: Vec<&str> = match registry {
+        Some(registry) => parse_registry(enum_name, registry)?,
+        None => Vec::new(),
+    };
+    let registered_names: HashSet<&str> = registry_names.iter().copied().collect();
+    let mut sorted_names = names.to_vec();
+    sorted_names.sort_unstable();
+
+    let ordered_names: Vec<&str> = match order {
+        EnumOrder::Appearance => names.to_vec(),
+        EnumOrder::Alphabetical => sorted_names,
+        EnumOrder::Registry => registry_names
+            .iter()
+            .copied()
+            .chain(
+                sorted_names
+                    .into_iter()
+                    .filter(|name| !registered_names.contains(name)),
+            )
+            .collect(),
+    };
+
+    let ids: HashMap<&str, usize> = ordered_names
+        .iter()
+        .enumerate()
+        .map(|(id, name)| (*name, id))
+        .collect();
+    for (registry_id, name) in registry_names.iter().enumerate() {
+        if let Some(&id) = ids.get(name) {
+            if id != registry_id {
+                return Err(format!(
+                    "the value of {} variant `{}` would change from {} to {}. use the registry enum order to keep \
+                     the values in the registry",
+                    enum_name, name, registry_id, id
+                ));
+            }
+        }
+    }
+
+    Ok(ordered_names.into_iter().map(str::to_string).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate_code, CCodeArgs};
+
+    use super::*;
+
+    const NAMES: &[&str] = &["add", "xor", "adc"];
+
+    /// variants keep the values listed in the registry, even ones which were removed, and new variants are appended
+    /// after them.
+    #[test]
+    fn registry_order_keeps_registered_values() {
+        assert_eq!(
+            assign_enum_ids("e", NAMES, EnumOrder::Registry, Some("xor\nremoved\nadd\n")).unwrap(),
+            ["xor", "removed", "add", "adc"]
+        );
+    }
+
+    #[test]
+    fn changing_a_registered_value_fails() {
+        let error =
+            assign_enum_ids("e", NAMES, EnumOrder::Alphabetical, Some("xor\n")).unwrap_err();
+        assert!(error.starts_with("the value of e variant `xor` would change from 0 to 2"));
+    }
+
+    #[test]
+    fn malformed_registries_are_rejected() {
+        for (registry, expected_error) in [
+            ("xor\n\nadd\n", "line 2 of the e registry is empty"),
+            (
+                "xor\nadd\nxor\n",
+                "line 3 of the e registry, `xor`, is already listed in an earlier line",
+            ),
+            (
+                "xor\nmov eax\n",
+                "line 2 of the e registry, `mov eax`, is not a valid variant name",
+            ),
+            (
+                "Xor\n",
+                "line 1 of the e registry, `Xor`, is not a valid variant name",
+            ),
+        ] {
+            assert_eq!(
+                assign_enum_ids("e", NAMES, EnumOrder::Registry, Some(registry)),
+                Err(expected_error.to_string())
+            );
+        }
+    }
+
+    /// a mistyped registry path must not silently start a new registry.
+    #[test]
+    fn missing_enum_registry_fails_unless_created() {
+        let path = std::env::temp_dir().join(format!(
+            "x86_tables_missing_enum_registry_{}",
+            std::process::id()
+        ));
+        let args = CCodeArgs {
+            enum_order: EnumOrder::Registry,
+            enum_registry: Some(path.clone()),
+            ..CCodeArgs::default()
+        };
+        assert!(generate_code(&args)
+            .err()
+            .unwrap()
+            .contains("pass --create-enum-registry to create it"));
+        assert!(generate_code(&CCodeArgs {
+            create_enum_registry: true,
+            ..args
+        })
+        .is_ok());
+    }
+
+    /// in orders other than the registry order, an existing registry is only checked, so that the names which were
+    /// removed from the tables keep their values in it.
+    #[test]
+    fn only_the_registry_order_rewrites_an_existing_registry() {
+        let path = std::env::temp_dir().join(format!(
+            "x86_tables_existing_enum_registry_{}",
+            std::process::id()
+        ));
+        std::fs::write(&path, "removed\n").unwrap();
+        let args = CCodeArgs {
+            enum_order: EnumOrder::Alphabetical,
+            enum_registry: Some(path.clone()),
+            ..CCodeArgs::default()
+        };
+        assert_eq!(generate_code(&args).unwrap().enum_registry, None);
+        let enum_registry = generate_code(&CCodeArgs {
+            enum_order: EnumOrder::Registry,
+            ..args
+        })
+        .unwrap()
+        .enum_registry
+        .unwrap();
+        assert!(enum_registry.starts_with("removed\n"));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn unreadable_enum_registry_fails() {
+        let args = CCodeArgs {
+            enum_order: EnumOrder::Registry,
+            enum_registry: Some(std::env::temp_dir()),
+            ..CCodeArgs::default()
+        };
+        assert!(generate_code(&args)
+            .err()
+            .unwrap()
+            .starts_with("failed to read the enum registry"));
+    }
+}
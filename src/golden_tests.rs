@@ -0,0 +1,112 @@
+//! snapshot tests of the generated c code.
+//!
+//! the generated headers are compared against the golden headers in the `golden` directory, so that every change to
+//! the generated code shows up in review. after a deliberate change, the golden headers can be updated by running the
+//! tests with `UPDATE_GOLDEN=1`.
+
+use std::path::PathBuf;
+
+use crate::{c_emitter::StructLayout, c_test_utils::compile_and_run, generate_code, CCodeArgs};
+
+fn golden_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("golden")
+}
+
+fn check_golden(file_name: &str, code: &str) {
+    let path = golden_dir().join(file_name);
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::create_dir_all(golden_dir()).unwrap();
+        std::fs::write(&path, code).unwrap();
+        return;
+    }
+    let golden = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {}", path.display(), err));
+    // the first differing line is reported instead of the whole file, since the tables are huge
+    if let Some((i, (line, golden_line))) = code
+        .lines()
+        .zip(golden.lines())
+        .enumerate()
+        .find(|(_, (line, golden_line))| line != golden_line)
+    {
+        panic!(
+            "{} differs from {} at line {}:\n  generated: {}\n  golden:    {}\nrun the tests with UPDATE_GOLDEN=1 if the change is deliberate",
+            file_name,
+            path.display(),
+            i + 1,
+            line,
+            golden_line
+        );
+    }
+    assert_eq!(
+        code.lines().count(),
+        golden.lines().count(),
+        "{} differs from {} in its amount of lines, run the tests with UPDATE_GOLDEN=1 if the change is deliberate",
+        file_name,
+        path.display()
+    );
+}
+
+#[test]
+fn types_file_matches_golden() {
+    check_golden(
+        "types.h",
+        generate_code(&CCodeArgs::default())
+            .unwrap()
+            .types_file
+            .code(),
+    );
+}
+
+#[test]
+fn tables_file_matches_golden() {
+    check_golden(
+        "tables.h",
+        generate_code(&CCodeArgs::default())
+            .unwrap()
+            .tables_file
+            .code(),
+    );
+}
+
+/// the expected sizes in bytes of the types whose layout determines the size of the tables.
+const EXPECTED_SIZES: &[(&str, usize)] = &[
+    ("insn_info_t", 3),
+    ("op_info_t", 2),
+    ("regular_insn_info_t", 3),
+];
+
+/// compiles the generated headers and checks the sizes of the table types, which must be the same in every layout.
+fn check_generated_types_have_expected_sizes(name: &str, struct_layout: StructLayout) {
+    let mut program = String::from(
+        "#include <stdio.h>\n#include \"types.h\"\n#include \"tables.h\"\nint main(void) {\n",
+    );
+    for (type_name, _) in EXPECTED_SIZES {
+        program.push_str(&format!("    printf(\"%zu\\n\", sizeof({}));\n", type_name));
+    }
+    program.push_str("    return 0;\n}\n");
+
+    let args = CCodeArgs {
+        struct_layout,
+        ..CCodeArgs::default()
+    };
+    let output = compile_and_run(&[args], name, &program);
+    let sizes: Vec<usize> = output.lines().map(|line| line.parse().unwrap()).collect();
+
+    for ((type_name, expected_size), size) in EXPECTED_SIZES.iter().zip(sizes) {
+        assert_eq!(
+            size, *expected_size,
+            "sizeof({}) changed, update the expected sizes if the change is deliberate",
+            type_name
+        );
+    }
+}
+
+#[test]
+fn generated_types_have_expected_sizes() {
+    check_generated_types_have_expected_sizes("sizes", StructLayout::Packed);
+}
+
+#[test]
+fn portable_generated_types_have_expected_sizes() {
+    check_generated_types_have_expected_sizes("portable_sizes", StructLayout::Portable);
+}
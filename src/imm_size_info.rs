@@ -0,0 +1,83 @@
+use crate::table_types::*;
+
+/// the total size in bytes of the immediate fields which follow an instruction, in each mode.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImmSizeInfo {
+    pub with_operand_size_override: usize,
+    pub mode_32: usize,
+    pub mode_64: usize,
+    /// near branches ignore the operand size override prefix in 64-bit mode, so unlike the operand sizes, the
+    /// immediate sizes with the prefix differ between the modes.
+    pub mode_64_with_operand_size_override: usize,
+    pub mode_64_with_rex_w: usize,
+    /// whether the immediates are followed by a memory offset, whose size is the address size rather than the operand
+    /// size, and is thus not included in the sizes above.
+    pub has_mem_offset: bool,
+}
+impl ImmSizeInfo {
+    pub fn of_insn(insn: &RegularInsnInfo) -> Self {
+        let mut imm_size_info = Self::default();
+        for op in insn.ops {
+            let (encoded_size, mode_64_with_operand_size_override) = match op {
+                OpInfo::Imm(imm) => (
+                    &imm.encoded_size,
+                    imm.encoded_size.with_operand_size_override,
+                ),
+                OpInfo::Rel(size) => (size, size.mode_64),
+                OpInfo::MemOffset(_) => {
+                    imm_size_info.has_mem_offset = true;
+                    continue;
+                }
+                _ => continue,
+            };
+            imm_size_info.with_operand_size_override +=
+                encoded_size.with_operand_size_override as usize / 8;
+            imm_size_info.mode_32 += encoded_size.mode_32 as usize / 8;
+            imm_size_info.mode_64 += encoded_size.mode_64 as usize / 8;
+            imm_size_info.mode_64_with_operand_size_override +=
+                mode_64_with_operand_size_override as usize / 8;
+            imm_size_info.mode_64_with_rex_w += encoded_size.mode_64_with_rex_w as usize / 8;
+        }
+        imm_size_info
+    }
+
+    /// the immediate sizes of an opcode byte table entry. returns `None` if they depend on the modrm byte, for example
+    /// in `F6 /0`, which is the only form of its group which has an immediate.
+    pub fn of_insn_info(insn_info: &InsnInfo) -> Option<Self> {
+        let insns: Vec<&RegularInsnInfo> = match insn_info {
+            InsnInfo::Regular(insn) => vec![insn],
+            InsnInfo::ModrmRegOpcodeExt(modrm_reg_opcode_ext) => {
+                modrm_reg_opcode_ext.by_reg_value.iter().collect()
+            }
+            InsnInfo::RepPrefixModrmExt(rep_prefix_modrm_ext) => {
+                std::iter::once(&rep_prefix_modrm_ext.default)
+                    .chain(rep_prefix_modrm_ext.overrides.iter().map(|x| &x.insn))
+                    .collect()
+            }
+        };
+        // the immediate sizes of unsupported instructions don't matter, since they can't be decoded anyway
+        let mut imm_size_infos = insns
+            .into_iter()
+            .filter(|insn| !insn.is_unsupported())
+            .map(Self::of_insn);
+        let first = imm_size_infos.next().unwrap_or_default();
+        if imm_size_infos.all(|imm_size_info| imm_size_info == first) {
+            Some(first)
+        } else {
+            None
+        }
+    }
+
+    pub fn max_size(&self) -> usize {
+        *[
+            self.with_operand_size_override,
+            self.mode_32,
+            self.mode_64,
+            self.mode_64_with_operand_size_override,
+            self.mode_64_with_rex_w,
+        ]
+        .iter()
+        .max()
+        .unwrap()
+    }
+}
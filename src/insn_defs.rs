@@ -0,0 +1,453 @@
+//! declarative instruction definitions.
+//!
+//! the opcode byte tables are generated from the rows of `insn_defs.toml`. each row is placed into the slot identified
+//! by its map, opcode and modrm constraints, which must not be defined by another row. slots which aren't defined by
+//! any row are unsupported.
+
+use std::{fmt, sync::OnceLock};
+
+use serde::{Deserialize, Deserializer};
+
+use crate::table_types::*;
+
+/// the name of the primary opcode map, which contains the one byte opcodes.
+pub const MAP_PRIMARY: &str = "primary";
+
+/// the name of the opcode map of the `0F` escape byte.
+pub const MAP_0F: &str = "0f";
+
+const INSN_DEFS_TOML: &str = include_str!("insn_defs.toml");
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct InsnDefsFile {
+    insn: Vec<InsnDef>,
+}
+
+/// a single instruction definition row. the fields match the ones used by the JSON export.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InsnDef {
+    pub map: String,
+    pub opcode: u8,
+    /// the value of the modrm reg field, for instructions which are further identified by it.
+    #[serde(default)]
+    pub modrm_reg: Option<u8>,
+    /// the value of the modrm byte, for instructions which are further identified by an F3 prefix and the modrm byte.
+    #[serde(default)]
+    pub rep_prefix_modrm: Option<u8>,
+    pub mnemonic: String,
+    #[serde(default)]
+    pub ops: Vec<OpDef>,
+    #[serde(default)]
+    pub rep_kind: RepKind,
+    #[serde(default)]
+    pub lockable: bool,
+}
+
+/// an operand, either referred to by the name of one of the `OpInfo` constants, or fully specified.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum OpDef {
+    Named(String),
+    Explicit(OpInfo),
+}
+
+/// an operand size, either referred to by the name of one of the `OpSizeInfo` constants, or fully specified.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OpSizeInfoDef {
+    Named(String),
+    Explicit {
+        with_operand_size_override: OpSize,
+        mode_32: OpSize,
+        mode_64: OpSize,
+        mode_64_with_rex_w: OpSize,
+    },
+}
+impl<'de> Deserialize<'de> for OpSizeInfo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match OpSizeInfoDef::deserialize(deserializer)? {
+            OpSizeInfoDef::Named(name) => named_op_size_info(&name).ok_or_else(|| {
+                serde::de::Error::custom(format!("unknown operand size {:?}", name))
+            }),
+            OpSizeInfoDef::Explicit {
+                with_operand_size_override,
+                mode_32,
+                mode_64,
+                mode_64_with_rex_w,
+            } => Ok(Self {
+                with_operand_size_override,
+                mode_32,
+                mode_64,
+                mode_64_with_rex_w,
+            }),
+        }
+    }
+}
+
+fn named_op_size_info(name: &str) -> Option<OpSizeInfo> {
+    OpSizeInfo::NAMED
+        .iter()
+        .find(|(const_name, _)| *const_name == name)
+        .map(|(_, op_size_info)| op_size_info.clone())
+}
+
+fn named_op_info(name: &str) -> Option<OpInfo> {
+    OpInfo::NAMED
+        .iter()
+        .find(|(const_name, _)| *const_name == name)
+        .map(|(_, op_info)| op_info.clone())
+}
+
+impl OpDef {
+    /// returns an operand definition for the given operand, which refers to it by name if possible.
+    pub fn new(op_info: OpInfo) -> Self {
+        match OpInfo::NAMED
+            .iter()
+            .find(|(_, named_op_info)| *named_op_info == op_info)
+        {
+            Some((name, _)) => Self::Named(name.to_string()),
+            None => Self::Explicit(op_info),
+        }
+    }
+
+    fn to_toml(&self) -> String {
+        match self {
+            OpDef::Named(name) => format!("{:?}", name),
+            OpDef::Explicit(op_info) => {
+                let mut value = toml::Value::try_from(op_info).unwrap();
+                name_op_sizes(&mut value);
+                // the kind is written before its info, like in the handwritten rows
+                let mut fields = vec![format!("kind = {}", value["kind"])];
+                if let Some(info) = value.get("info") {
+                    fields.push(format!("info = {}", info));
+                }
+                format!("{{ {} }}", fields.join(", "))
+            }
+        }
+    }
+}
+
+/// replaces each operand size within the given value with the name of the matching `OpSizeInfo` constant, if any.
+fn name_op_sizes(value: &mut toml::Value) {
+    if let Ok(op_size_info) = value.clone().try_into::<OpSizeInfo>() {
+        if let Some((name, _)) = OpSizeInfo::NAMED
+            .iter()
+            .find(|(_, named)| *named == op_size_info)
+        {
+            *value = toml::Value::String(name.to_string());
+        }
+        return;
+    }
+    if let toml::Value::Table(table) = value {
+        for (_, field) in table.iter_mut() {
+            name_op_sizes(field);
+        }
+    }
+}
+
+/// the reason why an instruction definition can't be placed into its opcode byte table.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ApplyError {
+    /// the instruction's slot is already defined.
+    AlreadyDefined,
+    /// the definition is invalid, or doesn't fit the instruction which is already defined at its opcode.
+    Invalid(String),
+}
+impl fmt::Display for ApplyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApplyError::AlreadyDefined => write!(f, "opcode is already defined"),
+            ApplyError::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl InsnDef {
+    /// a short description of the location that this row defines, used in error messages.
+    pub fn location(&self) -> String {
+        let mut location = format!("{} {:#04x}", self.map, self.opcode);
+        if let Some(reg_value) = self.modrm_reg {
+            location.push_str(&format!(" /{}", reg_value));
+        }
+        if let Some(modrm) = self.rep_prefix_modrm {
+            location.push_str(&format!(" with F3 prefix and modrm {:#04x}", modrm));
+        }
+        location
+    }
+
+    fn to_regular_insn_info(&self) -> Result<RegularInsnInfo, String> {
+        let ops = self
+            .ops
+            .iter()
+            .map(|op_def| match op_def {
+                OpDef::Named(name) => {
+                    named_op_info(name).ok_or_else(|| format!("unknown operand {:?}", name))
+                }
+                OpDef::Explicit(op_info) => Ok(op_info.clone()),
+            })
+            .collect::<Result<Vec<OpInfo>, String>>()?;
+        Ok(RegularInsnInfo {
+            // the tables are only generated once, so leaking the definitions is fine
+            mnemonic: self.mnemonic.clone().leak(),
+            ops: ops.leak(),
+            rep_kind: self.rep_kind.clone(),
+            lockable: self.lockable,
+        })
+    }
+
+    /// places the defined instruction into the given opcode byte table. fails without modifying the table if the
+    /// instruction's slot is already defined.
+    pub fn try_apply(&self, table: &mut [InsnInfo]) -> Result<(), ApplyError> {
+        let insn = self.to_regular_insn_info().map_err(ApplyError::Invalid)?;
+        let slot = &mut table[self.opcode as usize];
+        match (self.modrm_reg, self.rep_prefix_modrm) {
+            (None, None) => match slot {
+                InsnInfo::Regular(existing) if existing.is_unsupported() => {
+                    *existing = insn;
+                    Ok(())
+                }
+                _ => Err(ApplyError::AlreadyDefined),
+            },
+            (Some(reg_value), None) => {
+                if reg_value >= 8 {
+                    return Err(ApplyError::Invalid("invalid modrm reg value".to_string()));
+                }
+                if matches!(slot, InsnInfo::Regular(existing) if existing.is_unsupported()) {
+                    *slot = InsnInfo::ModrmRegOpcodeExt(ModrmRegOpcodeExtInsnInfo {
+                        by_reg_value: std::array::from_fn(|_| RegularInsnInfo::UNSUPPORTED),
+                    });
+                }
+                match slot {
+                    InsnInfo::ModrmRegOpcodeExt(modrm_reg_table)
+                        if modrm_reg_table.by_reg_value[reg_value as usize].is_unsupported() =>
+                    {
+                        modrm_reg_table.by_reg_value[reg_value as usize] = insn;
+                        Ok(())
+                    }
+                    _ => Err(ApplyError::AlreadyDefined),
+                }
+            }
+            (None, Some(modrm)) => match slot {
+                // the override is selected by the modrm byte, so the default instruction must have one as well.
+                InsnInfo::Regular(default)
+                    if !default.uses_modrm() && !default.is_unsupported() =>
+                {
+                    Err(ApplyError::Invalid(
+                        "the instruction at this opcode has no modrm byte".to_string(),
+                    ))
+                }
+                InsnInfo::Regular(default) => {
+                    *slot = InsnInfo::RepPrefixModrmExt(RepPrefixModrmExtInsnInfo {
+                        default: default.clone(),
+                        overrides: vec![RepPrefixModrmOverride { modrm, insn }].leak(),
+                    });
+                    Ok(())
+                }
+                InsnInfo::RepPrefixModrmExt(rep_prefix_modrm_table)
+                    if rep_prefix_modrm_table
+                        .overrides
+                        .iter()
+                        .all(|x| x.modrm != modrm) =>
+                {
+                    let mut overrides = rep_prefix_modrm_table.overrides.to_vec();
+                    overrides.push(RepPrefixModrmOverride { modrm, insn });
+                    rep_prefix_modrm_table.overrides = overrides.leak();
+                    Ok(())
+                }
+                _ => Err(ApplyError::AlreadyDefined),
+            },
+            (Some(_), Some(_)) => Err(ApplyError::Invalid(
+                "an instruction can't be identified by both the modrm reg field and an F3 prefix"
+                    .to_string(),
+            )),
+        }
+    }
+
+    /// formats this definition as an `[[insn]]` row of `insn_defs.toml`.
+    pub fn to_toml(&self) -> String {
+        let mut row = String::from("[[insn]]\n");
+        row.push_str(&format!("map = {:?}\n", self.map));
+        row.push_str(&format!("opcode = {:#04x}\n", self.opcode));
+        if let Some(reg_value) = self.modrm_reg {
+            row.push_str(&format!("modrm_reg = {}\n", reg_value));
+        }
+        if let Some(modrm) = self.rep_prefix_modrm {
+            row.push_str(&format!("rep_prefix_modrm = {:#04x}\n", modrm));
+        }
+        row.push_str(&format!("mnemonic = {:?}\n", self.mnemonic));
+        if !self.ops.is_empty() {
+            let ops: Vec<String> = self.ops.iter().map(|op_def| op_def.to_toml()).collect();
+            row.push_str(&format!("ops = [{}]\n", ops.join(", ")));
+        }
+        if self.rep_kind != RepKind::None {
+            row.push_str(&format!(
+                "rep_kind = {:?}\n",
+                toml::Value::try_from(&self.rep_kind)
+                    .unwrap()
+                    .as_str()
+                    .unwrap()
+            ));
+        }
+        if self.lockable {
+            row.push_str("lockable = true\n");
+        }
+        row
+    }
+}
+
+/// parses the rows of an instruction definitions file.
+fn parse_insn_defs(text: &str) -> Result<Vec<InsnDef>, String> {
+    let insn_defs_file: InsnDefsFile = toml::from_str(text).map_err(|err| err.to_string())?;
+    for insn_def in &insn_defs_file.insn {
+        if insn_def.map != MAP_PRIMARY && insn_def.map != MAP_0F {
+            return Err(format!("{}: unknown opcode map", insn_def.location()));
+        }
+    }
+    Ok(insn_defs_file.insn)
+}
+
+/// the rows of `insn_defs.toml`, which are only parsed once.
+fn builtin_insn_defs() -> Result<&'static [InsnDef], String> {
+    static INSN_DEFS: OnceLock<Result<Vec<InsnDef>, String>> = OnceLock::new();
+    INSN_DEFS
+        .get_or_init(|| parse_insn_defs(INSN_DEFS_TOML))
+        .as_deref()
+        .map_err(|err| format!("insn_defs.toml: {}", err))
+}
+
+/// generates the opcode byte table of the given map from the given instruction definitions.
+fn build_opcode_byte_table(insn_defs: &[InsnDef], map: &str) -> Result<Vec<InsnInfo>, String> {
+    let mut table = vec![InsnInfo::Regular(RegularInsnInfo::UNSUPPORTED); 0x100];
+    for insn_def in insn_defs.iter().filter(|insn_def| insn_def.map == map) {
+        insn_def
+            .try_apply(&mut table)
+            .map_err(|err| format!("{}: {}", insn_def.location(), err))?;
+    }
+    Ok(table)
+}
+
+/// generates the opcode byte table of the given map from its rows in `insn_defs.toml`.
+pub fn gen_opcode_byte_table(map: &str) -> Result<Vec<InsnInfo>, String> {
+    build_opcode_byte_table(builtin_insn_defs()?, map)
+        .map_err(|err| format!("insn_defs.toml: {}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(text: &str, map: &str) -> Result<Vec<InsnInfo>, String> {
+        build_opcode_byte_table(&parse_insn_defs(text)?, map)
+    }
+
+    #[test]
+    fn rows_are_placed_into_their_slots() {
+        let table = build(
+            r#"
+            [[insn]]
+            map = "0f"
+            opcode = 0x05
+            mnemonic = "syscall"
+
+            [[insn]]
+            map = "primary"
+            opcode = 0xf6
+            modrm_reg = 2
+            mnemonic = "not"
+            ops = ["RM_8"]
+            lockable = true
+            "#,
+            MAP_PRIMARY,
+        )
+        .unwrap();
+        // rows of other maps are ignored
+        assert!(matches!(&table[0x05], InsnInfo::Regular(insn) if insn.is_unsupported()));
+        let InsnInfo::ModrmRegOpcodeExt(modrm_reg_table) = &table[0xf6] else {
+            panic!("expected a modrm reg opcode extension");
+        };
+        assert_eq!(modrm_reg_table.by_reg_value[2].mnemonic, "not");
+        assert_eq!(modrm_reg_table.by_reg_value[2].ops, [OpInfo::RM_8]);
+        assert!(modrm_reg_table.by_reg_value[2].lockable);
+        assert!(modrm_reg_table.by_reg_value[3].is_unsupported());
+    }
+
+    #[test]
+    fn errors_are_reported_with_the_location_of_the_row() {
+        let duplicate = r#"
+            [[insn]]
+            map = "0f"
+            opcode = 0x05
+            mnemonic = "syscall"
+
+            [[insn]]
+            map = "0f"
+            opcode = 0x05
+            mnemonic = "sysret"
+            "#;
+        assert_eq!(
+            build(duplicate, MAP_0F).unwrap_err(),
+            "0f 0x05: opcode is already defined"
+        );
+
+        let unknown_map = r#"
+            [[insn]]
+            map = "0f38"
+            opcode = 0x00
+            mnemonic = "pshufb"
+            "#;
+        assert_eq!(
+            build(unknown_map, MAP_0F).unwrap_err(),
+            "0f38 0x00: unknown opcode map"
+        );
+
+        let unknown_operand = r#"
+            [[insn]]
+            map = "primary"
+            opcode = 0x00
+            mnemonic = "add"
+            ops = ["RM_9"]
+            "#;
+        assert_eq!(
+            build(unknown_operand, MAP_PRIMARY).unwrap_err(),
+            "primary 0x00: unknown operand \"RM_9\""
+        );
+    }
+
+    #[test]
+    fn already_defined_slots_are_distinguished_from_invalid_rows() {
+        let insn_defs = parse_insn_defs(
+            r#"
+            [[insn]]
+            map = "primary"
+            opcode = 0x90
+            mnemonic = "nop"
+
+            [[insn]]
+            map = "primary"
+            opcode = 0x90
+            mnemonic = "xchg"
+
+            [[insn]]
+            map = "primary"
+            opcode = 0x90
+            rep_prefix_modrm = 0xc0
+            mnemonic = "pause"
+            "#,
+        )
+        .unwrap();
+        let mut table = vec![InsnInfo::Regular(RegularInsnInfo::UNSUPPORTED); 0x100];
+        assert_eq!(insn_defs[0].try_apply(&mut table), Ok(()));
+        assert_eq!(
+            insn_defs[1].try_apply(&mut table),
+            Err(ApplyError::AlreadyDefined)
+        );
+        assert_eq!(
+            insn_defs[2].try_apply(&mut table),
+            Err(ApplyError::Invalid(
+                "the instruction at this opcode has no modrm byte".to_string()
+            ))
+        );
+    }
+}
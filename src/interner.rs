@@ -0,0 +1,60 @@
+//! interning of the entries of the emitted tables.
+//!
+//! an interner assigns each unique entry the index of its first occurrence, in a single pass, and then maps entries
+//! back to their indexes in constant time while emitting the tables which reference them.
+
+use std::{collections::HashMap, hash::Hash, ops::Deref};
+
+pub struct Interner<T> {
+    items: Vec<T>,
+    indexes: HashMap<T, usize>,
+}
+impl<T: Hash + Eq + Clone> Interner<T> {
+    /// returns the index of the given item, adding it if it was not interned yet.
+    pub fn intern(&mut self, item: T) -> usize {
+        if let Some(index) = self.indexes.get(&item) {
+            return *index;
+        }
+        let index = self.items.len();
+        self.indexes.insert(item.clone(), index);
+        self.items.push(item);
+        index
+    }
+
+    /// returns the index of the given item, which must already be interned.
+    pub fn index(&self, item: &T) -> usize {
+        *self.indexes.get(item).expect("the item was never interned")
+    }
+}
+impl<T> Default for Interner<T> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            indexes: HashMap::new(),
+        }
+    }
+}
+impl<T: Hash + Eq + Clone> FromIterator<T> for Interner<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut interner = Self::default();
+        for item in iter {
+            interner.intern(item);
+        }
+        interner
+    }
+}
+impl<T> Deref for Interner<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.items
+    }
+}
+impl<'a, T> IntoIterator for &'a Interner<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
@@ -0,0 +1,189 @@
+use serde::Serialize;
+
+use crate::table_types::*;
+
+/// a single supported instruction, as it appears in the exported JSON.
+#[derive(Serialize)]
+struct JsonInsnEntry<'a> {
+    /// the opcode map which contains the instruction, for example `0f` for the map of the `0F` escape byte.
+    map: &'static str,
+    opcode: u8,
+    /// the value of the modrm reg field, for instructions which are further identified by it.
+    modrm_reg: Option<u8>,
+    /// the value of the modrm byte, for instructions which are further identified by an F3 prefix and the modrm byte.
+    rep_prefix_modrm: Option<u8>,
+    /// whether other entries of the same opcode override this one for an F3 prefix and specific modrm bytes.
+    has_rep_prefix_modrm_overrides: bool,
+    #[serde(flatten)]
+    insn: &'a RegularInsnInfo,
+}
+
+fn collect_json_entries<'a>(
+    map: &'static str,
+    table: &'a [InsnInfo],
+    entries: &mut Vec<JsonInsnEntry<'a>>,
+) {
+    let mut push_entry = |opcode: usize,
+                          modrm_reg: Option<u8>,
+                          rep_prefix_modrm: Option<u8>,
+                          has_rep_prefix_modrm_overrides: bool,
+                          insn: &'a RegularInsnInfo| {
+        if insn.mnemonic == MNEMONIC_UNSUPPORTED {
+            return;
+        }
+        entries.push(JsonInsnEntry {
+            map,
+            opcode: opcode as u8,
+            modrm_reg,
+            rep_prefix_modrm,
+            has_rep_prefix_modrm_overrides,
+            insn,
+        })
+    };
+    for (opcode, insn_info) in table.iter().enumerate() {
+        match insn_info {
+            InsnInfo::Regular(info) => push_entry(opcode, None, None, false, info),
+            InsnInfo::ModrmRegOpcodeExt(modrm_reg_table) => {
+                for (reg_value, info) in modrm_reg_table.by_reg_value.iter().enumerate() {
+                    push_entry(opcode, Some(reg_value as u8), None, false, info)
+                }
+            }
+            InsnInfo::RepPrefixModrmExt(rep_prefix_modrm_table) => {
+                push_entry(
+                    opcode,
+                    None,
+                    None,
+                    !rep_prefix_modrm_table.overrides.is_empty(),
+                    &rep_prefix_modrm_table.default,
+                );
+                for rep_prefix_modrm_override in rep_prefix_modrm_table.overrides {
+                    push_entry(
+                        opcode,
+                        None,
+                        Some(rep_prefix_modrm_override.modrm),
+                        false,
+                        &rep_prefix_modrm_override.insn,
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// generates a JSON array describing every supported instruction in the given opcode maps.
+pub fn gen_json(maps: &[(&'static str, &[InsnInfo])]) -> String {
+    let mut entries = Vec::new();
+    for (map, table) in maps {
+        collect_json_entries(map, table, &mut entries);
+    }
+    serde_json::to_string_pretty(&entries).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::generate_json;
+
+    /// the single exported entry at the given location.
+    fn find_entry<'a>(
+        entries: &'a [Value],
+        map: &str,
+        opcode: u8,
+        modrm_reg: Option<u8>,
+        rep_prefix_modrm: Option<u8>,
+    ) -> &'a Value {
+        let mut matching = entries.iter().filter(|entry| {
+            entry["map"] == map
+                && entry["opcode"] == opcode
+                && entry["modrm_reg"] == json!(modrm_reg)
+                && entry["rep_prefix_modrm"] == json!(rep_prefix_modrm)
+        });
+        let entry = matching.next().expect("missing entry");
+        assert!(matching.next().is_none(), "repeated entry");
+        entry
+    }
+
+    fn size(bits: u32) -> Value {
+        json!({
+            "with_operand_size_override": bits,
+            "mode_32": bits,
+            "mode_64": bits,
+            "mode_64_with_rex_w": bits,
+        })
+    }
+
+    #[test]
+    fn json_contains_known_entries() {
+        let entries: Vec<Value> = serde_json::from_str(&generate_json().unwrap()).unwrap();
+
+        assert_eq!(
+            find_entry(&entries, "primary", 0x00, None, None),
+            &json!({
+                "map": "primary",
+                "opcode": 0x00,
+                "modrm_reg": null,
+                "rep_prefix_modrm": null,
+                "has_rep_prefix_modrm_overrides": false,
+                "mnemonic": "add",
+                "ops": [
+                    { "kind": "rm", "info": size(8) },
+                    { "kind": "reg", "info": { "encoding": "modrm", "size": size(8) } },
+                ],
+                "rep_kind": "none",
+                "lockable": true,
+            })
+        );
+
+        // a slot of a group which is identified by the modrm reg field
+        assert_eq!(
+            find_entry(&entries, "primary", 0x80, Some(7), None),
+            &json!({
+                "map": "primary",
+                "opcode": 0x80,
+                "modrm_reg": 7,
+                "rep_prefix_modrm": null,
+                "has_rep_prefix_modrm_overrides": false,
+                "mnemonic": "cmp",
+                "ops": [
+                    { "kind": "rm", "info": size(8) },
+                    {
+                        "kind": "imm",
+                        "info": {
+                            "encoded_size": size(8),
+                            "extended_size": size(8),
+                            "extend_kind": "sign_extend",
+                        },
+                    },
+                ],
+                "rep_kind": "none",
+                "lockable": false,
+            })
+        );
+
+        // the default entry of an opcode with rep prefix modrm overrides is marked as such
+        assert_eq!(
+            find_entry(&entries, "0f", 0x1e, None, None)["has_rep_prefix_modrm_overrides"],
+            true
+        );
+        assert_eq!(
+            find_entry(&entries, "0f", 0x1e, None, Some(0xfa)),
+            &json!({
+                "map": "0f",
+                "opcode": 0x1e,
+                "modrm_reg": null,
+                "rep_prefix_modrm": 0xfa,
+                "has_rep_prefix_modrm_overrides": false,
+                "mnemonic": "endbr64",
+                "ops": [],
+                "rep_kind": "none",
+                "lockable": false,
+            })
+        );
+
+        // unsupported slots are not exported
+        assert!(!entries
+            .iter()
+            .any(|entry| entry["map"] == "primary" && entry["opcode"] == 0x06));
+    }
+}
@@ -0,0 +1,119 @@
+// returns the length of the instruction at `code`, or 0 if the instruction is not supported, or if it is longer than
+// `max_len` bytes. `is_64_bit` selects between 64-bit mode and 32-bit mode.
+static inline size_t length_decoder_insn_len(const uint8_t* code, size_t max_len, int is_64_bit) {
+    size_t len = 0;
+    int has_operand_size_override = 0;
+    int has_address_size_override = 0;
+    int has_rex_w = 0;
+    if (max_len > LENGTH_DECODER_MAX_INSN_LEN) {
+        max_len = LENGTH_DECODER_MAX_INSN_LEN;
+    }
+
+    // legacy prefixes and REX prefixes. a REX prefix only takes effect if it comes right before the opcode, so if there
+    // are multiple REX prefixes the last one wins, and a REX prefix which is followed by a legacy prefix is ignored.
+    for (;;) {
+        if (len >= max_len) {
+            return 0;
+        }
+        uint8_t byte = code[len];
+        if (is_64_bit && (byte & 0xf0) == 0x40) {
+            has_rex_w = (byte & 0x08) != 0;
+        } else {
+            if (byte == 0x66) {
+                has_operand_size_override = 1;
+            } else if (byte == 0x67) {
+                has_address_size_override = 1;
+            } else if (byte != 0xf0 && byte != 0xf2 && byte != 0xf3 && byte != 0x26 && byte != 0x2e &&
+                       byte != 0x36 && byte != 0x3e && byte != 0x64 && byte != 0x65) {
+                break;
+            }
+            has_rex_w = 0;
+        }
+        len++;
+    }
+
+    // the opcode map
+    const uint8_t* has_modrm_table = length_decoder_first_opcode_byte_has_modrm;
+    const uint8_t* imm_classes_table = length_decoder_first_opcode_byte_imm_classes;
+    if (code[len] == 0x0f) {
+        has_modrm_table = length_decoder_second_opcode_byte_has_modrm;
+        imm_classes_table = length_decoder_second_opcode_byte_imm_classes;
+        len++;
+        if (len >= max_len) {
+            return 0;
+        }
+    }
+    uint8_t opcode = code[len];
+    len++;
+    uint8_t imm_class = imm_classes_table[opcode];
+
+    // the modrm byte, the sib byte and the displacement
+    if ((has_modrm_table[opcode >> 3] >> (opcode & 7)) & 1) {
+        if (len >= max_len) {
+            return 0;
+        }
+        uint8_t modrm = code[len];
+        len++;
+        uint8_t mod = modrm >> 6;
+        uint8_t rm = modrm & 7;
+        if (imm_class >= LENGTH_DECODER_FIRST_MODRM_REG_IMM_CLASS) {
+            imm_class = length_decoder_modrm_reg_imm_classes[(imm_class - LENGTH_DECODER_FIRST_MODRM_REG_IMM_CLASS) * 8 +
+                                                             ((modrm >> 3) & 7)];
+        }
+        if (!is_64_bit && has_address_size_override) {
+            // 16-bit addressing
+            if ((mod == 0 && rm == 6) || mod == 2) {
+                len += 2;
+            } else if (mod == 1) {
+                len += 1;
+            }
+        } else {
+            if (mod != 3 && rm == 4) {
+                if (len >= max_len) {
+                    return 0;
+                }
+                uint8_t sib = code[len];
+                len++;
+                if (mod == 0 && (sib & 7) == 5) {
+                    len += 4;
+                }
+            }
+            if ((mod == 0 && rm == 5) || mod == 2) {
+                len += 4;
+            } else if (mod == 1) {
+                len += 1;
+            }
+        }
+    }
+    if (imm_class == LENGTH_DECODER_IMM_CLASS_INVALID) {
+        return 0;
+    }
+
+    // the immediates
+    const length_decoder_imm_size_t* imm_size = &length_decoder_imm_sizes[imm_class];
+    if (is_64_bit) {
+        if (has_rex_w) {
+            len += imm_size->mode_64_with_rex_w;
+        } else if (has_operand_size_override) {
+            len += imm_size->mode_64_with_operand_size_override;
+        } else {
+            len += imm_size->mode_64;
+        }
+    } else if (has_operand_size_override) {
+        len += imm_size->with_operand_size_override;
+    } else {
+        len += imm_size->mode_32;
+    }
+    if (imm_size->has_mem_offset) {
+        if (is_64_bit) {
+            len += has_address_size_override ? 4 : 8;
+        } else {
+            len += has_address_size_override ? 2 : 4;
+        }
+    }
+
+    if (len > max_len) {
+        return 0;
+    }
+    return len;
+}
@@ -0,0 +1,294 @@
+//! generation of a standalone instruction length decoder.
+//!
+//! the length decoder only has to know whether each opcode has a modrm byte, and the sizes of its immediates, so it
+//! uses its own tiny tables instead of the full instruction info tables. the decoding logic itself is handwritten in
+//! `length_decoder.c`, and is emitted after the tables.
+
+use crate::{
+    begin_include_guard, c_emitter::CEmitter, end_include_guard, imm_size_info::ImmSizeInfo,
+    table_types::*, CCodeArgs,
+};
+
+/// the name of the generated length decoder header.
+pub const LENGTH_DECODER_FILE_NAME: &str = "length_decoder.h";
+
+const LENGTH_DECODER_C: &str = include_str!("length_decoder.c");
+
+/// the longest legal x86 instruction.
+const MAX_INSN_LEN: usize = 15;
+
+/// the immediate class of each opcode in the given table. the class is either an index into the unique immediate
+/// sizes, `imm_class_invalid` for unsupported opcodes, or `first_modrm_reg_imm_class` plus an index into the modrm reg
+/// immediate classes, for opcodes whose immediate sizes depend on the modrm reg field.
+fn opcode_imm_classes(
+    opcode_byte_table: &[InsnInfo],
+    uniq_imm_size_infos: &[ImmSizeInfo],
+    uniq_modrm_reg_imm_classes: &[[usize; 8]],
+    imm_class_invalid: usize,
+    first_modrm_reg_imm_class: usize,
+) -> Vec<usize> {
+    opcode_byte_table
+        .iter()
+        .map(|insn_info| {
+            match insn_info_imm_class(insn_info, uniq_imm_size_infos, imm_class_invalid) {
+                Some(imm_class) => imm_class,
+                None => {
+                    let modrm_reg_imm_classes =
+                        modrm_reg_imm_classes(insn_info, uniq_imm_size_infos, imm_class_invalid);
+                    first_modrm_reg_imm_class
+                        + uniq_modrm_reg_imm_classes
+                            .iter()
+                            .position(|x| *x == modrm_reg_imm_classes)
+                            .unwrap()
+                }
+            }
+        })
+        .collect()
+}
+
+/// the immediate class of an opcode, or `None` if it depends on the modrm reg field.
+fn insn_info_imm_class(
+    insn_info: &InsnInfo,
+    uniq_imm_size_infos: &[ImmSizeInfo],
+    imm_class_invalid: usize,
+) -> Option<usize> {
+    if !insn_info.is_supported() {
+        return Some(imm_class_invalid);
+    }
+    // groups which have unsupported reg values must be looked up by the reg value, so that those are rejected
+    if let InsnInfo::ModrmRegOpcodeExt(modrm_reg_opcode_ext) = insn_info {
+        if modrm_reg_opcode_ext
+            .by_reg_value
+            .iter()
+            .any(RegularInsnInfo::is_unsupported)
+        {
+            return None;
+        }
+    }
+    let imm_size_info = match ImmSizeInfo::of_insn_info(insn_info) {
+        Some(imm_size_info) => imm_size_info,
+        None => {
+            assert!(
+                matches!(insn_info, InsnInfo::ModrmRegOpcodeExt(_)),
+                "the immediate sizes of {:?} depend on the modrm byte",
+                insn_info
+            );
+            return None;
+        }
+    };
+    Some(
+        uniq_imm_size_infos
+            .iter()
+            .position(|x| *x == imm_size_info)
+            .unwrap(),
+    )
+}
+
+fn modrm_reg_imm_classes(
+    insn_info: &InsnInfo,
+    uniq_imm_size_infos: &[ImmSizeInfo],
+    imm_class_invalid: usize,
+) -> [usize; 8] {
+    let InsnInfo::ModrmRegOpcodeExt(modrm_reg_opcode_ext) = insn_info else {
+        unreachable!()
+    };
+    std::array::from_fn(|reg_value| {
+        insn_info_imm_class(
+            &InsnInfo::Regular(modrm_reg_opcode_ext.by_reg_value[reg_value].clone()),
+            uniq_imm_size_infos,
+            imm_class_invalid,
+        )
+        .unwrap()
+    })
+}
+
+fn table_all_imm_size_infos(
+    opcode_byte_table: &[InsnInfo],
+) -> impl Iterator<Item = ImmSizeInfo> + '_ {
+    opcode_byte_table
+        .iter()
+        .map(|insn_info| match insn_info {
+            InsnInfo::ModrmRegOpcodeExt(modrm_reg_opcode_ext) => modrm_reg_opcode_ext
+                .by_reg_value
+                .iter()
+                .map(ImmSizeInfo::of_insn)
+                .collect(),
+            _ => ImmSizeInfo::of_insn_info(insn_info).into_iter().collect(),
+        })
+        .flat_map(|imm_size_infos: Vec<ImmSizeInfo>| imm_size_infos)
+}
+
+/// generates a header containing the length decoder for the given opcode byte tables. only the symbol prefix and the
+/// include guard of the given options apply to it.
+pub fn gen_length_decoder(
+    first_opcode_byte_table: &[InsnInfo],
+    second_opcode_byte_table: &[InsnInfo],
+    args: &CCodeArgs,
+) -> CEmitter {
+    let opcode_byte_tables = [first_opcode_byte_table, second_opcode_byte_table];
+
+    let mut uniq_imm_size_infos: Vec<ImmSizeInfo> = Vec::new();
+    for imm_size_info in opcode_byte_tables
+        .iter()
+        .flat_map(|table| table_all_imm_size_infos(table))
+    {
+        if !uniq_imm_size_infos.contains(&imm_size_info) {
+            uniq_imm_size_infos.push(imm_size_info);
+        }
+    }
+    let imm_class_invalid = uniq_imm_size_infos.len();
+    let first_modrm_reg_imm_class = imm_class_invalid + 1;
+
+    let mut uniq_modrm_reg_imm_classes: Vec<[usize; 8]> = Vec::new();
+    for insn_info in opcode_byte_tables.iter().flat_map(|table| table.iter()) {
+        if insn_info_imm_class(insn_info, &uniq_imm_size_infos, imm_class_invalid).is_none() {
+            let modrm_reg_imm_classes =
+                modrm_reg_imm_classes(insn_info, &uniq_imm_size_infos, imm_class_invalid);
+            if !uniq_modrm_reg_imm_classes.contains(&modrm_reg_imm_classes) {
+                uniq_modrm_reg_imm_classes.push(modrm_reg_imm_classes);
+            }
+        }
+    }
+    assert!(
+        first_modrm_reg_imm_class + uniq_modrm_reg_imm_classes.len() <= 0x100,
+        "too many immediate classes"
+    );
+
+    let mut emitter = CEmitter::with_symbol_prefix(&args.symbol_prefix);
+    begin_include_guard(&mut emitter, args.include_guard, LENGTH_DECODER_FILE_NAME);
+    emitter.include_system("stddef.h");
+    emitter.include_system("stdint.h");
+    emitter.define("LENGTH_DECODER_MAX_INSN_LEN", &MAX_INSN_LEN.to_string());
+    emitter.define(
+        "LENGTH_DECODER_IMM_CLASS_INVALID",
+        &imm_class_invalid.to_string(),
+    );
+    emitter.define(
+        "LENGTH_DECODER_FIRST_MODRM_REG_IMM_CLASS",
+        &first_modrm_reg_imm_class.to_string(),
+    );
+
+    emitter
+        .begin_struct("length_decoder_imm_size_t")
+        .field("uint8_t", "with_operand_size_override")
+        .field("uint8_t", "mode_32")
+        .field("uint8_t", "mode_64")
+        .field("uint8_t", "mode_64_with_operand_size_override")
+        .field("uint8_t", "mode_64_with_rex_w")
+        .field("uint8_t", "has_mem_offset")
+        .emit();
+
+    let mut imm_sizes_table = emitter.begin_table(
+        &emitter.symbol("length_decoder_imm_size_t"),
+        "length_decoder_imm_sizes",
+    );
+    for imm_size_info in &uniq_imm_size_infos {
+        imm_sizes_table
+            .begin_entry()
+            .field_int(
+                "with_operand_size_override",
+                imm_size_info.with_operand_size_override,
+            )
+            .field_int("mode_32", imm_size_info.mode_32)
+            .field_int("mode_64", imm_size_info.mode_64)
+            .field_int(
+                "mode_64_with_operand_size_override",
+                imm_size_info.mode_64_with_operand_size_override,
+            )
+            .field_int("mode_64_with_rex_w", imm_size_info.mode_64_with_rex_w)
+            .field_int("has_mem_offset", imm_size_info.has_mem_offset as usize)
+            .emit();
+    }
+    imm_sizes_table.emit();
+
+    // the classes of each group are laid out consecutively, indexed by the modrm reg field
+    let mut modrm_reg_imm_classes_table =
+        emitter.begin_table("uint8_t", "length_decoder_modrm_reg_imm_classes");
+    for imm_class in uniq_modrm_reg_imm_classes.iter().flatten() {
+        modrm_reg_imm_classes_table.int_entry(*imm_class);
+    }
+    modrm_reg_imm_classes_table.emit();
+
+    for (opcode_byte_table, table_name_prefix) in [
+        (first_opcode_byte_table, "length_decoder_first_opcode_byte"),
+        (
+            second_opcode_byte_table,
+            "length_decoder_second_opcode_byte",
+        ),
+    ] {
+        emitter.emit_bitmap(
+            &format!("{}_has_modrm", table_name_prefix),
+            opcode_byte_table.iter().map(InsnInfo::has_modrm),
+        );
+        let mut imm_classes_table =
+            emitter.begin_table("uint8_t", &format!("{}_imm_classes", table_name_prefix));
+        for imm_class in opcode_imm_classes(
+            opcode_byte_table,
+            &uniq_imm_size_infos,
+            &uniq_modrm_reg_imm_classes,
+            imm_class_invalid,
+            first_modrm_reg_imm_class,
+        ) {
+            imm_classes_table.int_entry(imm_class);
+        }
+        imm_classes_table.emit();
+    }
+
+    // the handwritten code refers to the symbols by their unprefixed names
+    let length_decoder_c = LENGTH_DECODER_C
+        .replace("length_decoder_", &emitter.symbol("length_decoder_"))
+        .replace("LENGTH_DECODER_", &emitter.symbol("LENGTH_DECODER_"));
+    emitter.raw(&length_decoder_c);
+    end_include_guard(&mut emitter, args.include_guard);
+    emitter
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        c_test_utils::compile_and_run_sources,
+        first_opcode_byte_table::gen_first_opcode_byte_table,
+        second_opcode_byte_table::gen_second_opcode_byte_table, IncludeGuard,
+    };
+
+    use super::*;
+
+    /// two length decoders which are generated with different symbol prefixes must be usable in a single program.
+    #[test]
+    fn prefixed_length_decoders_dont_collide() {
+        let first_opcode_byte_table = gen_first_opcode_byte_table().unwrap();
+        let second_opcode_byte_table = gen_second_opcode_byte_table().unwrap();
+        let length_decoder = |args: &CCodeArgs| {
+            gen_length_decoder(&first_opcode_byte_table, &second_opcode_byte_table, args)
+                .code()
+                .to_string()
+        };
+        let prefixed = length_decoder(&CCodeArgs {
+            symbol_prefix: "x86_32_".to_string(),
+            include_guard: IncludeGuard::Ifndef,
+            ..CCodeArgs::default()
+        });
+        assert!(prefixed.starts_with("#ifndef X86_32_LENGTH_DECODER_H\n"));
+        let program = r#"#include <stdio.h>
+#include "length_decoder.h"
+#include "x86_32_length_decoder.h"
+#include "x86_32_length_decoder.h"
+int main(void) {
+    static const uint8_t code[] = {0x04, 0x01};
+    printf("%zu %zu\n", length_decoder_insn_len(code, sizeof(code), 1), x86_32_length_decoder_insn_len(code, sizeof(code), 0));
+    printf("%d\n", X86_32_LENGTH_DECODER_MAX_INSN_LEN);
+    return 0;
+}
+"#;
+        let output = compile_and_run_sources(
+            &[],
+            "prefixed_length_decoders",
+            &[
+                ("length_decoder.h", &length_decoder(&CCodeArgs::default())),
+                ("x86_32_length_decoder.h", &prefixed),
+            ],
+            &[("prefixed_length_decoders", program)],
+        );
+        assert_eq!(output, "2 2\n15\n");
+    }
+}
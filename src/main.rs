@@ -1,21 +1,55 @@
-use std::{cmp::max, path::PathBuf};
+use std::{
+    cmp::max,
+    path::{Path, PathBuf},
+};
 
-use c_emitter::{min_int_type_required_for_field, CEmitter, CStructValueEmitter};
-use clap::Parser;
+use c_emitter::{
+    min_int_type_required_for_field, CEmitter, CStructValueEmitter, CodeStyle, StructLayout,
+    TableLinkage,
+};
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
 use delve::VariantNames;
 use either::Either;
+use enum_ids::{assign_enum_ids, EnumOrder};
 use first_opcode_byte_table::gen_first_opcode_byte_table;
+use imm_size_info::ImmSizeInfo;
+use insn_defs::{ApplyError, MAP_0F, MAP_PRIMARY};
+use interner::Interner;
+use json_exporter::gen_json;
+use length_decoder::{gen_length_decoder, LENGTH_DECODER_FILE_NAME};
+use ops_layout::{lay_out_ops, OpsLayout};
 use second_opcode_byte_table::gen_second_opcode_byte_table;
+use table_stats::{DedupStats, TablesStats};
 use table_types::*;
+use table_validator::validate_table;
 use to_snake_case::ToSnakeCase;
+use xed_importer::import_xed;
 
 mod c_emitter;
+#[cfg(test)]
+mod c_test_utils;
+#[cfg(test)]
+mod differential_tests;
+mod enum_ids;
 mod first_opcode_byte_table;
+#[cfg(test)]
+mod golden_tests;
+mod imm_size_info;
+mod insn_defs;
+mod interner;
+mod json_exporter;
+mod length_decoder;
+mod ops_layout;
+#[cfg(test)]
+mod roundtrip_tests;
 mod second_opcode_byte_table;
-mod table_gen_utils;
+mod table_stats;
 mod table_types;
+mod table_validator;
+mod xed_importer;
 
 const MNEMONIC_MODRM_REG_OPCODE_EXT: &str = "modrm_reg_opcode_ext";
+const MNEMONIC_REP_PREFIX_MODRM_EXT: &str = "rep_prefix_modrm_ext";
 
 fn table_all_regular_insn_infos<'a>(
     table: &'a [InsnInfo],
@@ -25,8 +59,12 @@ fn table_all_regular_insn_infos<'a>(
         .map(|insn_info| match insn_info {
             InsnInfo::Regular(info) => Either::Left(std::iter::once(info)),
             InsnInfo::ModrmRegOpcodeExt(modrm_reg_opcode_ext_info) => {
-                Either::Right(modrm_reg_opcode_ext_info.by_reg_value.iter())
+                Either::Right(Either::Left(modrm_reg_opcode_ext_info.by_reg_value.iter()))
             }
+            InsnInfo::RepPrefixModrmExt(rep_prefix_modrm_ext_info) => Either::Right(Either::Right(
+                std::iter::once(&rep_prefix_modrm_ext_info.default)
+                    .chain(rep_prefix_modrm_ext_info.overrides.iter().map(|x| &x.insn)),
+            )),
         })
         .flatten()
 }
@@ -43,12 +81,21 @@ fn table_all_modrm_reg_opcode_ext_tables<'a>(
     table: &'a [InsnInfo],
 ) -> impl Iterator<Item = &'a ModrmRegOpcodeExtInsnInfo> + 'a {
     table.iter().filter_map(|insn_info| match insn_info {
-        InsnInfo::Regular(_) => None,
         InsnInfo::ModrmRegOpcodeExt(inner_table) => Some(inner_table),
+        _ => None,
     })
 }
 
-fn mnemonic_to_c_variant_name(mnemonic: Mnemonic) -> String {
+fn table_all_rep_prefix_modrm_ext_tables<'a>(
+    table: &'a [InsnInfo],
+) -> impl Iterator<Item = &'a RepPrefixModrmExtInsnInfo> + 'a {
+    table.iter().filter_map(|insn_info| match insn_info {
+        InsnInfo::RepPrefixModrmExt(inner_table) => Some(inner_table),
+        _ => None,
+    })
+}
+
+fn mnemonic_to_c_variant_name(mnemonic: &str) -> String {
     format!("MNEMONIC_{}", mnemonic.to_uppercase())
 }
 
@@ -89,108 +136,251 @@ fn specific_imm_to_c_variant_name(specific_imm_value_variant_name: &str) -> Stri
     )
 }
 
-fn op_size_to_c_variant_name(op_size: OpSize) -> String {
-    format!("OP_SIZE_{}", op_size as u32)
-}
-
-fn vec_dedup<T: Eq>(vec: &mut Vec<T>) {
-    let mut i = 0;
-    while i < vec.len() {
-        // find and remove duplicates
-        let mut j = i + 1;
-        while j < vec.len() {
-            if vec[i] == vec[j] {
-                // found duplicate, remove it
-                vec.remove(j);
-            } else {
-                j += 1;
-            }
-        }
-
-        i += 1;
-    }
-}
-
-fn iter_collect_unique<T: Eq, I: Iterator<Item = T>>(iter: I) -> Vec<T> {
-    let mut result: Vec<T> = iter.collect();
-    vec_dedup(&mut result);
-    result
-}
-
-fn find_index<'a, T: Eq + 'a, C>(item: &T, collection: C) -> usize
-where
-    C: IntoIterator<Item = &'a T>,
-{
-    collection.into_iter().position(|x| x == item).unwrap()
+fn rep_kind_to_c_variant_name(rep_kind_variant_name: &str) -> String {
+    format!(
+        "REP_KIND_{}",
+        rep_kind_variant_name.to_snake_case().to_uppercase()
+    )
 }
 
-fn find_first_op_index(ops_info: Ops, uniq_ops_infos: &[Ops]) -> usize {
-    uniq_ops_infos
-        .iter()
-        .take_while(|x| **x != ops_info)
-        .map(|x| x.len())
-        .sum()
+fn op_size_to_c_variant_name(op_size: OpSize) -> String {
+    format!("OP_SIZE_{}", op_size as u32)
 }
 
 fn emit_regular_insn_info(
     emitter: CStructValueEmitter,
     info: &RegularInsnInfo,
-    uniq_ops_infos: &[Ops],
+    ops_layout: &OpsLayout,
 ) {
     emitter
-        .field("mnemonic", &mnemonic_to_c_variant_name(info.mnemonic))
-        .field_int(
-            "first_op_index",
-            find_first_op_index(info.ops, uniq_ops_infos),
-        )
+        .field_symbol("mnemonic", &mnemonic_to_c_variant_name(info.mnemonic))
+        .field_int("first_op_index", ops_layout.first_op_index(info.ops))
         .field_int("ops_amount", info.ops.len())
+        .field_symbol(
+            "rep_kind",
+            &rep_kind_to_c_variant_name((&info.rep_kind).into()),
+        )
+        .field_int("lockable", info.lockable as usize)
         .emit()
 }
 
+/// a short description of an operand, for the comments of the emitted tables. sizes are given for 32 bit mode.
+fn describe_op(op: &OpInfo) -> String {
+    match op {
+        OpInfo::Imm(imm) => format!("imm{}", imm.encoded_size.mode_32 as u32),
+        OpInfo::SpecificImm(specific_imm) => <&str>::from(&specific_imm.value).to_snake_case(),
+        OpInfo::Reg(reg) => format!("reg{}", reg.size.mode_32 as u32),
+        OpInfo::Rm(size) => format!("rm{}", size.mode_32 as u32),
+        OpInfo::SpecificReg(specific_reg) => <&str>::from(&specific_reg.reg).to_snake_case(),
+        OpInfo::ZextSpecificReg(zext_specific_reg) => {
+            <&str>::from(&zext_specific_reg.reg).to_snake_case()
+        }
+        OpInfo::AddrSizeSpecificReg(reg) => <&str>::from(reg).to_snake_case(),
+        OpInfo::Rel(size) => format!("rel{}", size.mode_32 as u32),
+        OpInfo::MemOffset(mem_offset) => {
+            format!("moffs{}", mem_offset.mem_operand_size.mode_32 as u32)
+        }
+        OpInfo::Implicit(size) => format!("implicit{}", size.mode_32 as u32),
+        OpInfo::Cond => "cond".to_string(),
+    }
+}
+
+fn describe_regular_insn_info(info: &RegularInsnInfo) -> String {
+    let ops: Vec<String> = info.ops.iter().map(describe_op).collect();
+    if ops.is_empty() {
+        info.mnemonic.to_string()
+    } else {
+        format!("{} {}", info.mnemonic, ops.join(", "))
+    }
+}
+
+fn describe_insn_info(insn_info: &InsnInfo) -> String {
+    match insn_info {
+        InsnInfo::Regular(info) => describe_regular_insn_info(info),
+        InsnInfo::ModrmRegOpcodeExt(modrm_reg_table) => format!(
+            "by modrm reg: {}",
+            modrm_reg_table
+                .by_reg_value
+                .iter()
+                .map(|info| info.mnemonic)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        InsnInfo::RepPrefixModrmExt(rep_prefix_modrm_table) => format!(
+            "{}, with f3 prefix by modrm: {}",
+            describe_regular_insn_info(&rep_prefix_modrm_table.default),
+            rep_prefix_modrm_table
+                .overrides
+                .iter()
+                .map(|x| x.insn.mnemonic)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
 fn emit_opcode_byte_table(
     tables_file: &mut CEmitter,
     opcode_byte_table: &[InsnInfo],
     table_name: &str,
-    uniq_ops_infos: &[Ops],
-    uniq_modrm_reg_opcode_ext_tables: &[ModrmRegOpcodeExtInsnInfo],
+    ops_layout: &OpsLayout,
+    uniq_modrm_reg_opcode_ext_tables: &Interner<ModrmRegOpcodeExtInsnInfo>,
+    uniq_rep_prefix_modrm_ext_tables: &Interner<RepPrefixModrmExtInsnInfo>,
 ) {
-    let mut table_emitter = tables_file.begin_table("insn_info_t", table_name);
-    for insn_info in opcode_byte_table {
+    let mut table_emitter = tables_file.begin_table(&tables_file.symbol("insn_info_t"), table_name);
+    for (opcode, insn_info) in opcode_byte_table.iter().enumerate() {
         let mut entry = table_emitter.begin_entry();
         match insn_info {
             InsnInfo::Regular(info) => {
-                emit_regular_insn_info(entry.begin_struct_field("regular"), info, uniq_ops_infos)
+                emit_regular_insn_info(entry.begin_struct_field("regular"), info, ops_layout)
             }
             InsnInfo::ModrmRegOpcodeExt(modrm_reg_table) => entry
                 .begin_struct_field("modrm_reg_opcode_ext")
-                .field(
+                .field_symbol(
                     "mnemonic",
                     &mnemonic_to_c_variant_name(MNEMONIC_MODRM_REG_OPCODE_EXT),
                 )
                 .field_int(
                     "modrm_reg_table_index",
-                    find_index(modrm_reg_table, uniq_modrm_reg_opcode_ext_tables),
+                    uniq_modrm_reg_opcode_ext_tables.index(modrm_reg_table),
+                )
+                .emit(),
+            InsnInfo::RepPrefixModrmExt(rep_prefix_modrm_table) => entry
+                .begin_struct_field("rep_prefix_modrm_ext")
+                .field_symbol(
+                    "mnemonic",
+                    &mnemonic_to_c_variant_name(MNEMONIC_REP_PREFIX_MODRM_EXT),
+                )
+                .field_int(
+                    "rep_prefix_modrm_table_index",
+                    uniq_rep_prefix_modrm_ext_tables.index(rep_prefix_modrm_table),
                 )
                 .emit(),
         }
         entry.emit();
+        table_emitter.comment(&format!(
+            "{:#04x}: {}",
+            opcode,
+            describe_insn_info(insn_info)
+        ));
     }
     table_emitter.emit();
 }
 
+/// emits the per opcode tables which let a decoder find the length of an instruction without walking its operands. the
+/// modrm bits and the immediate sizes are derived from the operands, so they are only meaningful for the opcodes whose
+/// bit is set in the `*_supported` bitmap.
+fn emit_fast_path_tables(
+    tables_file: &mut CEmitter,
+    opcode_byte_table: &[InsnInfo],
+    table_name_prefix: &str,
+) {
+    tables_file.emit_bitmap(
+        &format!("{}_supported", table_name_prefix),
+        opcode_byte_table.iter().map(InsnInfo::is_supported),
+    );
+    tables_file.emit_bitmap(
+        &format!("{}_has_modrm", table_name_prefix),
+        opcode_byte_table.iter().map(InsnInfo::has_modrm),
+    );
+
+    let mut imm_size_table = tables_file.begin_table(
+        &tables_file.symbol("imm_size_info_t"),
+        &format!("{}_imm_size_by_mode", table_name_prefix),
+    );
+    for (opcode, insn_info) in opcode_byte_table.iter().enumerate() {
+        let imm_size_info = ImmSizeInfo::of_insn_info(insn_info);
+        let depends_on_modrm = imm_size_info.is_none();
+        let imm_size_info = imm_size_info.unwrap_or_default();
+        imm_size_table
+            .begin_entry()
+            .field_int(
+                "with_operand_size_override",
+                imm_size_info.with_operand_size_override,
+            )
+            .field_int("mode_32", imm_size_info.mode_32)
+            .field_int("mode_64", imm_size_info.mode_64)
+            .field_int(
+                "mode_64_with_operand_size_override",
+                imm_size_info.mode_64_with_operand_size_override,
+            )
+            .field_int("mode_64_with_rex_w", imm_size_info.mode_64_with_rex_w)
+            .field_int("has_mem_offset", imm_size_info.has_mem_offset as usize)
+            .field_int("depends_on_modrm", depends_on_modrm as usize)
+            .emit();
+        imm_size_table.comment(&format!("{:#04x}", opcode));
+    }
+    imm_size_table.emit();
+}
+
+/// the name of the `#ifndef` include guard of the given file, for example `TYPES_H` for `types.h`.
+fn ifndef_guard_name(file_name: &str) -> String {
+    file_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn begin_include_guard(emitter: &mut CEmitter, include_guard: IncludeGuard, file_name: &str) {
+    match include_guard {
+        IncludeGuard::PragmaOnce => emitter.pragma_once(),
+        IncludeGuard::Ifndef => emitter.begin_ifndef_guard(&ifndef_guard_name(file_name)),
+    }
+}
+
+fn end_include_guard(emitter: &mut CEmitter, include_guard: IncludeGuard) {
+    match include_guard {
+        IncludeGuard::PragmaOnce => {}
+        IncludeGuard::Ifndef => emitter.end_ifndef_guard(),
+    }
+}
+
 struct GeneratedCode {
     types_file: CEmitter,
     tables_file: CEmitter,
+    /// the source file containing the table definitions, if they are not defined in the tables header.
+    tables_source_file: Option<CEmitter>,
+    stats: TablesStats,
+    /// the new contents of the id registry, listing the names of the mnemonics in the order of their values, if it
+    /// should be written. in orders other than the registry order, mnemonics which were removed from the tables would
+    /// lose their values, so an existing registry is only checked.
+    enum_registry: Option<String>,
 }
-fn generate_code() -> GeneratedCode {
-    let mut types_file = CEmitter::new();
-    let mut tables_file = CEmitter::new();
+fn generate_code(args: &CCodeArgs) -> Result<GeneratedCode, String> {
+    let mut types_file = CEmitter::with_symbol_prefix(&args.symbol_prefix);
+    // in source mode, the tables are emitted into the source file, and the tables header is built from their
+    // declarations once they are all emitted.
+    let mut tables_file = CEmitter::with_symbol_prefix(&args.symbol_prefix);
+
+    types_file.set_struct_layout(args.struct_layout);
+    tables_file.set_struct_layout(args.struct_layout);
+    types_file.set_code_style(args.code_style);
+    tables_file.set_code_style(args.code_style);
 
-    types_file.pragma_once();
+    begin_include_guard(&mut types_file, args.include_guard, &args.types_file_name);
+    match args.tables_mode {
+        TablesMode::Header => {
+            begin_include_guard(&mut tables_file, args.include_guard, &args.tables_file_name)
+        }
+        TablesMode::Static => {
+            tables_file.set_table_linkage(TableLinkage::Static);
+            begin_include_guard(&mut tables_file, args.include_guard, &args.tables_file_name);
+        }
+        TablesMode::Source => {
+            tables_file.set_table_linkage(TableLinkage::Extern);
+            tables_file.include_local(&args.tables_file_name);
+        }
+    }
     types_file.include_system("stdint.h");
 
-    let first_opcode_byte_table = gen_first_opcode_byte_table();
-    let second_opcode_byte_table = gen_second_opcode_byte_table();
+    let first_opcode_byte_table = gen_first_opcode_byte_table()?;
+    let second_opcode_byte_table = gen_second_opcode_byte_table()?;
 
     let combined_table = [
         first_opcode_byte_table.as_slice(),
@@ -198,14 +388,45 @@ fn generate_code() -> GeneratedCode {
     ]
     .concat();
 
-    let mut uniq_mnemonics = iter_collect_unique(table_all_mnemonics(&combined_table));
+    let mut uniq_mnemonics: Interner<_> = table_all_mnemonics(&combined_table).collect();
     // a psuedo mnemonic used to represent the fact that this instruction required further identification using the reg field
     // of the modrm field.
-    uniq_mnemonics.push(MNEMONIC_MODRM_REG_OPCODE_EXT);
+    uniq_mnemonics.intern(MNEMONIC_MODRM_REG_OPCODE_EXT);
+    // a psuedo mnemonic used to represent the fact that this instruction requires further identification using the
+    // F3 prefix and the modrm byte.
+    uniq_mnemonics.intern(MNEMONIC_REP_PREFIX_MODRM_EXT);
+    if args.enum_order == EnumOrder::Registry && args.enum_registry.is_none() {
+        return Err("the registry enum order requires an --enum-registry file".to_string());
+    }
+    let registry = match &args.enum_registry {
+        Some(path) if !path.exists() => {
+            if !args.create_enum_registry {
+                return Err(format!(
+                    "the enum registry {} does not exist. pass --create-enum-registry to create it",
+                    path.display()
+                ));
+            }
+            None
+        }
+        Some(path) => Some(std::fs::read_to_string(path).map_err(|err| {
+            format!(
+                "failed to read the enum registry {}: {}",
+                path.display(),
+                err
+            )
+        })?),
+        None => None,
+    };
+    let mnemonic_names = assign_enum_ids(
+        "mnemonic_t",
+        &uniq_mnemonics,
+        args.enum_order,
+        registry.as_deref(),
+    )?;
 
-    let uniq_ops_infos = iter_collect_unique(table_all_ops(&combined_table));
-    let laid_out_ops_infos = uniq_ops_infos.iter().map(|x| x.iter()).flatten();
-    let laid_out_ops_infos_len = laid_out_ops_infos.clone().count();
+    let uniq_ops_infos: Interner<_> = table_all_ops(&combined_table).collect();
+    let ops_layout = lay_out_ops(&uniq_ops_infos);
+    let laid_out_ops_infos_len = ops_layout.ops_infos.len();
     let insn_max_ops = uniq_ops_infos
         .iter()
         .map(|cur_ops| cur_ops.len())
@@ -214,39 +435,74 @@ fn generate_code() -> GeneratedCode {
 
     types_file.define("X86_TABLES_INSN_MAX_OPS", &insn_max_ops.to_string());
 
-    let uniq_op_infos = iter_collect_unique(laid_out_ops_infos.cloned());
-    let uniq_op_size_infos = iter_collect_unique(
-        uniq_op_infos
-            .iter()
-            .map(|op_info| match op_info {
-                OpInfo::Imm(imm) => vec![imm.encoded_size.clone(), imm.extended_size.clone()],
-                OpInfo::SpecificImm(imm) => vec![imm.operand_size.clone()],
-                OpInfo::Reg(reg) => vec![reg.size.clone()],
-                OpInfo::Rm(size) => vec![size.clone()],
-                OpInfo::SpecificReg(reg) => vec![reg.size.clone()],
-                OpInfo::ZextSpecificReg(reg) => vec![reg.size.clone(), reg.extended_size.clone()],
-                OpInfo::Rel(size) => vec![size.clone()],
-                OpInfo::MemOffset(moffset) => vec![moffset.mem_operand_size.clone()],
-                OpInfo::Implicit(size) => vec![size.clone()],
-                OpInfo::Cond => vec![],
-            })
-            .flatten(),
-    );
+    let uniq_op_infos: Interner<_> = uniq_ops_infos
+        .iter()
+        .flat_map(|x| x.iter())
+        .cloned()
+        .collect();
+    let op_size_infos: Vec<OpSizeInfo> = uniq_op_infos
+        .iter()
+        .map(|op_info| match op_info {
+            OpInfo::Imm(imm) => vec![imm.encoded_size.clone(), imm.extended_size.clone()],
+            OpInfo::SpecificImm(imm) => vec![imm.operand_size.clone()],
+            OpInfo::Reg(reg) => vec![reg.size.clone()],
+            OpInfo::Rm(size) => vec![size.clone()],
+            OpInfo::SpecificReg(reg) => vec![reg.size.clone()],
+            OpInfo::ZextSpecificReg(reg) => vec![reg.size.clone(), reg.extended_size.clone()],
+            OpInfo::AddrSizeSpecificReg(_) => vec![],
+            OpInfo::Rel(size) => vec![size.clone()],
+            OpInfo::MemOffset(moffset) => vec![moffset.mem_operand_size.clone()],
+            OpInfo::Implicit(size) => vec![size.clone()],
+            OpInfo::Cond => vec![],
+        })
+        .flatten()
+        .collect();
+    let uniq_op_size_infos: Interner<_> = op_size_infos.iter().cloned().collect();
 
-    let uniq_modrm_reg_opcode_ext_tables =
-        iter_collect_unique(table_all_modrm_reg_opcode_ext_tables(&combined_table).cloned());
+    let uniq_modrm_reg_opcode_ext_tables = table_all_modrm_reg_opcode_ext_tables(&combined_table)
+        .cloned()
+        .collect::<Interner<_>>();
+
+    let uniq_rep_prefix_modrm_ext_tables = table_all_rep_prefix_modrm_ext_tables(&combined_table)
+        .cloned()
+        .collect::<Interner<_>>();
+    let laid_out_rep_prefix_modrm_overrides = uniq_rep_prefix_modrm_ext_tables
+        .iter()
+        .map(|x| x.overrides.iter())
+        .flatten();
+    let laid_out_rep_prefix_modrm_overrides_len =
+        laid_out_rep_prefix_modrm_overrides.clone().count();
+    let rep_prefix_modrm_max_overrides = uniq_rep_prefix_modrm_ext_tables
+        .iter()
+        .map(|x| x.overrides.len())
+        .max()
+        .unwrap();
+
+    let imm_max_size = combined_table
+        .iter()
+        .filter_map(ImmSizeInfo::of_insn_info)
+        .map(|imm_size_info| imm_size_info.max_size())
+        .max()
+        .unwrap();
 
     types_file.emit_enum(
         "mnemonic_t",
         "MNEMONIC_",
-        uniq_mnemonics
+        mnemonic_names.iter().map(|x| mnemonic_to_c_variant_name(x)),
+    );
+
+    types_file.emit_enum(
+        "rep_kind_t",
+        "REP_KIND_",
+        RepKind::VARIANT_NAMES
             .iter()
-            .map(|x| mnemonic_to_c_variant_name(*x)),
+            .map(|x| rep_kind_to_c_variant_name(x)),
     );
 
+    let regular_insn_info_type = types_file.symbol("regular_insn_info_t");
     types_file
         .begin_struct("regular_insn_info_t")
-        .bit_field("mnemonic", uniq_mnemonics.len())
+        .bit_field("mnemonic", mnemonic_names.len())
         .bit_field(
             "first_op_index",
             max(
@@ -255,19 +511,29 @@ fn generate_code() -> GeneratedCode {
             ),
         )
         .bit_field("ops_amount", insn_max_ops + 1)
+        .bit_field("rep_kind", RepKind::VARIANT_NAMES.len())
+        .bit_field("lockable", 2)
         .emit();
 
     let mut insn_info_union = types_file.begin_union("insn_info_t");
-    insn_info_union.bit_field("mnemonic", uniq_mnemonics.len());
-    insn_info_union.field("regular_insn_info_t", "regular");
+    insn_info_union.bit_field("mnemonic", mnemonic_names.len());
+    insn_info_union.field(&regular_insn_info_type, "regular");
     insn_info_union
         .begin_embedded_struct("modrm_reg_opcode_ext")
-        .bit_field("mnemonic", uniq_mnemonics.len())
+        .bit_field("mnemonic", mnemonic_names.len())
         .bit_field(
             "modrm_reg_table_index",
             uniq_modrm_reg_opcode_ext_tables.len(),
         )
         .emit();
+    insn_info_union
+        .begin_embedded_struct("rep_prefix_modrm_ext")
+        .bit_field("mnemonic", mnemonic_names.len())
+        .bit_field(
+            "rep_prefix_modrm_table_index",
+            uniq_rep_prefix_modrm_ext_tables.len(),
+        )
+        .emit();
     insn_info_union.emit();
 
     types_file.emit_enum(
@@ -318,6 +584,14 @@ fn generate_code() -> GeneratedCode {
             .map(|x| specific_imm_to_c_variant_name(x)),
     );
 
+    types_file.emit_enum(
+        "imm_ext_kind_t",
+        "IMM_EXT_KIND_",
+        ImmExtendKind::VARIANT_NAMES
+            .iter()
+            .map(|x| imm_ext_kind_to_c_variant_name(x)),
+    );
+
     let mut op_info_union = types_file.begin_tagged_union("op_info_t", OpInfo::VARIANT_NAMES.len());
     op_info_union
         .begin_struct_variant("imm")
@@ -350,6 +624,10 @@ fn generate_code() -> GeneratedCode {
         .bit_field("extended_size_info_index", uniq_op_size_infos.len())
         .bit_field("reg", SpecificReg::VARIANT_NAMES.len())
         .emit();
+    op_info_union
+        .begin_struct_variant("addr_size_specific_reg")
+        .bit_field("reg", SpecificReg::VARIANT_NAMES.len())
+        .emit();
     op_info_union
         .begin_struct_variant("rel")
         .bit_field("size_info_index", uniq_op_size_infos.len())
@@ -367,29 +645,101 @@ fn generate_code() -> GeneratedCode {
 
     types_file
         .begin_struct("modrm_reg_opcode_ext_table_t")
-        .array_field("regular_insn_info_t", "by_reg_value", 8)
+        .array_field(&regular_insn_info_type, "by_reg_value", 8)
+        .emit();
+
+    types_file
+        .begin_struct("rep_prefix_modrm_override_t")
+        .bit_field("modrm", 256)
+        .field(&regular_insn_info_type, "insn")
         .emit();
 
-    let mut op_size_info_table = tables_file.begin_table("op_size_info_t", "op_size_infos_table");
-    for op_size_info in &uniq_op_size_infos {
+    types_file
+        .begin_struct("rep_prefix_modrm_ext_table_t")
+        .field(&regular_insn_info_type, "default_insn")
+        .bit_field(
+            "first_override_index",
+            laid_out_rep_prefix_modrm_overrides_len,
+        )
+        .bit_field("overrides_amount", rep_prefix_modrm_max_overrides + 1)
+        .emit();
+
+    // the total size in bytes of the immediates of each opcode, in each mode. if the size depends on the modrm byte, it
+    // must be found by looking up the instruction itself.
+    types_file
+        .begin_struct("imm_size_info_t")
+        .bit_field("with_operand_size_override", imm_max_size + 1)
+        .bit_field("mode_32", imm_max_size + 1)
+        .bit_field("mode_64", imm_max_size + 1)
+        .bit_field("mode_64_with_operand_size_override", imm_max_size + 1)
+        .bit_field("mode_64_with_rex_w", imm_max_size + 1)
+        .bit_field("has_mem_offset", 2)
+        .bit_field("depends_on_modrm", 2)
+        .emit();
+
+    // tests a bit in one of the `*_has_modrm` bitmaps, which is only meaningful for supported opcodes
+    types_file.define(
+        "X86_TABLES_HAS_MODRM(has_modrm_table, opcode)",
+        "(((has_modrm_table)[(opcode) >> 3] >> ((opcode) & 7)) & 1)",
+    );
+    // tests a bit in one of the `*_supported` bitmaps, which is set if any instruction of the opcode is supported
+    types_file.define(
+        "X86_TABLES_IS_SUPPORTED(supported_table, opcode)",
+        "(((supported_table)[(opcode) >> 3] >> ((opcode) & 7)) & 1)",
+    );
+
+    tables_file.import_types(&types_file);
+    tables_file.emit_string_table("mnemonic_names", &mnemonic_names);
+    tables_file.emit_string_table(
+        "op_size_names",
+        OpSize::VARIANT_NAMES
+            .iter()
+            .map(|x| x.strip_prefix("S").unwrap()),
+    );
+    tables_file.emit_string_table(
+        "op_kind_names",
+        OpInfo::VARIANT_NAMES.iter().map(|x| x.to_snake_case()),
+    );
+    tables_file.emit_string_table(
+        "reg_encoding_names",
+        RegEncoding::VARIANT_NAMES.iter().map(|x| x.to_snake_case()),
+    );
+    tables_file.emit_string_table(
+        "specific_reg_names",
+        SpecificReg::VARIANT_NAMES.iter().map(|x| x.to_snake_case()),
+    );
+    tables_file.emit_string_table(
+        "specific_imm_names",
+        SpecificImm::VARIANT_NAMES.iter().map(|x| x.to_snake_case()),
+    );
+    tables_file.emit_string_table(
+        "rep_kind_names",
+        RepKind::VARIANT_NAMES.iter().map(|x| x.to_snake_case()),
+    );
+
+    let mut op_size_info_table =
+        tables_file.begin_table(&tables_file.symbol("op_size_info_t"), "op_size_infos_table");
+    for (i, op_size_info) in uniq_op_size_infos.iter().enumerate() {
         op_size_info_table
             .begin_entry()
-            .field(
+            .field_symbol(
                 "with_operand_size_override",
                 &op_size_to_c_variant_name(op_size_info.with_operand_size_override),
             )
-            .field("mode_32", &op_size_to_c_variant_name(op_size_info.mode_32))
-            .field("mode_64", &op_size_to_c_variant_name(op_size_info.mode_64))
-            .field(
+            .field_symbol("mode_32", &op_size_to_c_variant_name(op_size_info.mode_32))
+            .field_symbol("mode_64", &op_size_to_c_variant_name(op_size_info.mode_64))
+            .field_symbol(
                 "mode_64_with_rex_w",
                 &op_size_to_c_variant_name(op_size_info.mode_64_with_rex_w),
             )
             .emit();
+        op_size_info_table.comment(&i.to_string());
     }
     op_size_info_table.emit();
 
-    let mut op_info_table = tables_file.begin_table("op_info_t", "op_infos_table");
-    for op_info in &uniq_op_infos {
+    let mut op_info_table =
+        tables_file.begin_table(&tables_file.symbol("op_info_t"), "op_infos_table");
+    for (i, op_info) in uniq_op_infos.iter().enumerate() {
         let mut entry = op_info_table.begin_entry();
 
         let op_kind_c_variant = op_kind_to_c_variant_name(op_info.into());
@@ -397,104 +747,104 @@ fn generate_code() -> GeneratedCode {
         match op_info {
             OpInfo::Imm(imm) => entry
                 .begin_struct_field("imm")
-                .field("kind", &op_kind_c_variant)
+                .field_symbol("kind", &op_kind_c_variant)
                 .field_int(
                     "encoded_size_info_index",
-                    find_index(&imm.encoded_size, &uniq_op_size_infos),
+                    uniq_op_size_infos.index(&imm.encoded_size),
                 )
                 .field_int(
                     "extended_size_info_index",
-                    find_index(&imm.extended_size, &uniq_op_size_infos),
+                    uniq_op_size_infos.index(&imm.extended_size),
                 )
-                .field(
+                .field_symbol(
                     "extend_kind",
                     &imm_ext_kind_to_c_variant_name((&imm.extend_kind).into()),
                 )
                 .emit(),
             OpInfo::SpecificImm(specific_imm) => entry
                 .begin_struct_field("specific_imm")
-                .field("kind", &op_kind_c_variant)
+                .field_symbol("kind", &op_kind_c_variant)
                 .field_int(
                     "operand_size_info_index",
-                    find_index(&specific_imm.operand_size, &uniq_op_size_infos),
+                    uniq_op_size_infos.index(&specific_imm.operand_size),
                 )
-                .field(
+                .field_symbol(
                     "value",
                     &specific_imm_to_c_variant_name((&specific_imm.value).into()),
                 )
                 .emit(),
             OpInfo::Reg(reg) => entry
                 .begin_struct_field("reg")
-                .field("kind", &op_kind_c_variant)
-                .field_int(
-                    "size_info_index",
-                    find_index(&reg.size, &uniq_op_size_infos),
-                )
-                .field(
+                .field_symbol("kind", &op_kind_c_variant)
+                .field_int("size_info_index", uniq_op_size_infos.index(&reg.size))
+                .field_symbol(
                     "encoding",
                     &reg_encoding_to_c_variant_name((&reg.encoding).into()),
                 )
                 .emit(),
             OpInfo::Rm(rm_size) => entry
                 .begin_struct_field("rm")
-                .field("kind", &op_kind_c_variant)
-                .field_int("size_info_index", find_index(rm_size, &uniq_op_size_infos))
+                .field_symbol("kind", &op_kind_c_variant)
+                .field_int("size_info_index", uniq_op_size_infos.index(rm_size))
                 .emit(),
             OpInfo::SpecificReg(specific_reg) => entry
                 .begin_struct_field("specific_reg")
-                .field("kind", &op_kind_c_variant)
+                .field_symbol("kind", &op_kind_c_variant)
                 .field_int(
                     "size_info_index",
-                    find_index(&specific_reg.size, &uniq_op_size_infos),
+                    uniq_op_size_infos.index(&specific_reg.size),
                 )
-                .field(
+                .field_symbol(
                     "reg",
                     &specific_reg_to_c_variant_name((&specific_reg.reg).into()),
                 )
                 .emit(),
             OpInfo::ZextSpecificReg(zext_specific_reg) => entry
                 .begin_struct_field("zext_specific_reg")
-                .field("kind", &op_kind_c_variant)
+                .field_symbol("kind", &op_kind_c_variant)
                 .field_int(
                     "size_info_index",
-                    find_index(&zext_specific_reg.size, &uniq_op_size_infos),
+                    uniq_op_size_infos.index(&zext_specific_reg.size),
                 )
                 .field_int(
                     "extended_size_info_index",
-                    find_index(&zext_specific_reg.extended_size, &uniq_op_size_infos),
+                    uniq_op_size_infos.index(&zext_specific_reg.extended_size),
                 )
-                .field(
+                .field_symbol(
                     "reg",
                     &specific_reg_to_c_variant_name((&zext_specific_reg.reg).into()),
                 )
                 .emit(),
+            OpInfo::AddrSizeSpecificReg(reg) => entry
+                .begin_struct_field("addr_size_specific_reg")
+                .field_symbol("kind", &op_kind_c_variant)
+                .field_symbol("reg", &specific_reg_to_c_variant_name(reg.into()))
+                .emit(),
             OpInfo::Rel(rel_size) => entry
                 .begin_struct_field("rel")
-                .field("kind", &op_kind_c_variant)
-                .field_int("size_info_index", find_index(rel_size, &uniq_op_size_infos))
+                .field_symbol("kind", &op_kind_c_variant)
+                .field_int("size_info_index", uniq_op_size_infos.index(rel_size))
                 .emit(),
             OpInfo::MemOffset(mem_offset) => entry
                 .begin_struct_field("mem_offset")
-                .field("kind", &op_kind_c_variant)
+                .field_symbol("kind", &op_kind_c_variant)
                 .field_int(
                     "mem_operand_size_info_index",
-                    find_index(&mem_offset.mem_operand_size, &uniq_op_size_infos),
+                    uniq_op_size_infos.index(&mem_offset.mem_operand_size),
                 )
                 .emit(),
             OpInfo::Implicit(implicit_size) => entry
                 .begin_struct_field("implicit")
-                .field("kind", &op_kind_c_variant)
-                .field_int(
-                    "size_info_index",
-                    find_index(implicit_size, &uniq_op_size_infos),
-                )
+                .field_symbol("kind", &op_kind_c_variant)
+                .field_int("size_info_index", uniq_op_size_infos.index(implicit_size))
                 .emit(),
             OpInfo::Cond => entry
                 .begin_struct_field("cond")
-                .field("kind", &op_kind_c_variant)
+                .field_symbol("kind", &op_kind_c_variant)
                 .emit(),
         }
         entry.emit();
+        op_info_table.comment(&format!("{}: {}", i, describe_op(op_info)));
     }
     op_info_table.emit();
 
@@ -502,68 +852,491 @@ fn generate_code() -> GeneratedCode {
         &min_int_type_required_for_field(uniq_op_infos.len()),
         "laid_out_ops_infos_table",
     );
-    for &ops_info in &uniq_ops_infos {
-        for op_info in ops_info {
-            laid_out_ops_infos_table.int_entry(find_index(op_info, &uniq_op_infos))
-        }
+    for (i, op_info) in ops_layout.ops_infos.iter().enumerate() {
+        laid_out_ops_infos_table.int_entry(uniq_op_infos.index(op_info));
+        laid_out_ops_infos_table.comment(&format!("{}: {}", i, describe_op(op_info)));
     }
     laid_out_ops_infos_table.emit();
 
     let mut modrm_reg_opcode_ext_tables = tables_file.begin_table(
-        "modrm_reg_opcode_ext_table_t",
+        &tables_file.symbol("modrm_reg_opcode_ext_table_t"),
         "modrm_reg_opcode_ext_tables",
     );
-    for inner_table in &uniq_modrm_reg_opcode_ext_tables {
+    for (i, inner_table) in uniq_modrm_reg_opcode_ext_tables.iter().enumerate() {
         let mut entry = modrm_reg_opcode_ext_tables.begin_entry();
         let mut by_reg_value_array = entry.begin_array_field("by_reg_value");
         for reg_value_entry in &inner_table.by_reg_value {
             emit_regular_insn_info(
                 by_reg_value_array.begin_struct_element(),
                 reg_value_entry,
-                &uniq_ops_infos,
+                &ops_layout,
             );
         }
         by_reg_value_array.emit();
         entry.emit();
+        modrm_reg_opcode_ext_tables.comment(&i.to_string());
     }
     modrm_reg_opcode_ext_tables.emit();
 
+    let mut rep_prefix_modrm_overrides_table = tables_file.begin_table(
+        &tables_file.symbol("rep_prefix_modrm_override_t"),
+        "rep_prefix_modrm_overrides_table",
+    );
+    for (i, rep_prefix_modrm_override) in laid_out_rep_prefix_modrm_overrides.enumerate() {
+        let mut entry = rep_prefix_modrm_overrides_table
+            .begin_entry()
+            .field_int("modrm", rep_prefix_modrm_override.modrm as usize);
+        emit_regular_insn_info(
+            entry.begin_struct_field("insn"),
+            &rep_prefix_modrm_override.insn,
+            &ops_layout,
+        );
+        entry.emit();
+        rep_prefix_modrm_overrides_table.comment(&format!(
+            "{}: modrm {:#04x}: {}",
+            i,
+            rep_prefix_modrm_override.modrm,
+            describe_regular_insn_info(&rep_prefix_modrm_override.insn)
+        ));
+    }
+    rep_prefix_modrm_overrides_table.emit();
+
+    let mut rep_prefix_modrm_ext_tables = tables_file.begin_table(
+        &tables_file.symbol("rep_prefix_modrm_ext_table_t"),
+        "rep_prefix_modrm_ext_tables",
+    );
+    let mut first_override_index = 0;
+    for (i, inner_table) in uniq_rep_prefix_modrm_ext_tables.iter().enumerate() {
+        let mut entry = rep_prefix_modrm_ext_tables.begin_entry();
+        emit_regular_insn_info(
+            entry.begin_struct_field("default_insn"),
+            &inner_table.default,
+            &ops_layout,
+        );
+        entry
+            .field_int("first_override_index", first_override_index)
+            .field_int("overrides_amount", inner_table.overrides.len())
+            .emit();
+        first_override_index += inner_table.overrides.len();
+        rep_prefix_modrm_ext_tables.comment(&i.to_string());
+    }
+    rep_prefix_modrm_ext_tables.emit();
+
     emit_opcode_byte_table(
         &mut tables_file,
         &first_opcode_byte_table,
         "first_opcode_byte_table",
-        &uniq_ops_infos,
+        &ops_layout,
         &uniq_modrm_reg_opcode_ext_tables,
+        &uniq_rep_prefix_modrm_ext_tables,
     );
     emit_opcode_byte_table(
         &mut tables_file,
         &second_opcode_byte_table,
         "second_opcode_byte_table",
-        &uniq_ops_infos,
+        &ops_layout,
         &uniq_modrm_reg_opcode_ext_tables,
+        &uniq_rep_prefix_modrm_ext_tables,
     );
-    GeneratedCode {
-        types_file,
-        tables_file,
+    emit_fast_path_tables(
+        &mut tables_file,
+        &first_opcode_byte_table,
+        "first_opcode_byte",
+    );
+    emit_fast_path_tables(
+        &mut tables_file,
+        &second_opcode_byte_table,
+        "second_opcode_byte",
+    );
+
+    let stats = TablesStats {
+        tables: tables_file.table_stats().to_vec(),
+        bit_fields: types_file.bit_field_stats(),
+        dedup_layers: vec![
+            DedupStats {
+                name: "op lists",
+                entries_before: table_all_ops(&combined_table).map(|ops| ops.len()).sum(),
+                entries_after: uniq_ops_infos.iter().map(|ops| ops.len()).sum(),
+                entry_size: types_file
+                    .type_size(min_int_type_required_for_field(uniq_op_infos.len())),
+            },
+            DedupStats {
+                name: "op list overlaps",
+                entries_before: uniq_ops_infos.iter().map(|ops| ops.len()).sum(),
+                entries_after: laid_out_ops_infos_len,
+                entry_size: types_file
+                    .type_size(min_int_type_required_for_field(uniq_op_infos.len())),
+            },
+            DedupStats {
+                name: "op infos",
+                entries_before: laid_out_ops_infos_len,
+                entries_after: uniq_op_infos.len(),
+                entry_size: types_file.type_size(&types_file.symbol("op_info_t")),
+            },
+            DedupStats {
+                name: "op size infos",
+                entries_before: op_size_infos.len(),
+                entries_after: uniq_op_size_infos.len(),
+                entry_size: types_file.type_size(&types_file.symbol("op_size_info_t")),
+            },
+            DedupStats {
+                name: "modrm reg opcode ext tables",
+                entries_before: table_all_modrm_reg_opcode_ext_tables(&combined_table).count(),
+                entries_after: uniq_modrm_reg_opcode_ext_tables.len(),
+                entry_size: types_file
+                    .type_size(&types_file.symbol("modrm_reg_opcode_ext_table_t")),
+            },
+            DedupStats {
+                name: "rep prefix modrm ext tables",
+                entries_before: table_all_rep_prefix_modrm_ext_tables(&combined_table).count(),
+                entries_after: uniq_rep_prefix_modrm_ext_tables.len(),
+                entry_size: types_file
+                    .type_size(&types_file.symbol("rep_prefix_modrm_ext_table_t")),
+            },
+        ],
+    };
+
+    let should_write_enum_registry = args.enum_registry.is_some()
+        && (args.enum_order == EnumOrder::Registry || registry.is_none());
+    let enum_registry = should_write_enum_registry.then(|| {
+        mnemonic_names
+            .iter()
+            .map(|name| format!("{}\n", name))
+            .collect()
+    });
+
+    end_include_guard(&mut types_file, args.include_guard);
+    match args.tables_mode {
+        TablesMode::Header | TablesMode::Static => {
+            end_include_guard(&mut tables_file, args.include_guard);
+            Ok(GeneratedCode {
+                types_file,
+                tables_file,
+                tables_source_file: None,
+                stats,
+                enum_registry,
+            })
+        }
+        TablesMode::Source => {
+            let mut tables_header = CEmitter::with_symbol_prefix(&args.symbol_prefix);
+            tables_header.set_code_style(args.code_style);
+            begin_include_guard(
+                &mut tables_header,
+                args.include_guard,
+                &args.tables_file_name,
+            );
+            tables_header.include_local(&args.types_file_name);
+            tables_header.raw(tables_file.table_declarations());
+            end_include_guard(&mut tables_header, args.include_guard);
+            Ok(GeneratedCode {
+                types_file,
+                tables_file: tables_header,
+                tables_source_file: Some(tables_file),
+                stats,
+                enum_registry,
+            })
+        }
     }
 }
 
+fn generate_json() -> Result<String, String> {
+    let first_opcode_byte_table = gen_first_opcode_byte_table()?;
+    let second_opcode_byte_table = gen_second_opcode_byte_table()?;
+    Ok(gen_json(&[
+        (MAP_PRIMARY, &first_opcode_byte_table),
+        (MAP_0F, &second_opcode_byte_table),
+    ]))
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    /// C headers containing the table types and the tables themselves
+    C,
+    /// a JSON file describing every supported instruction
+    Json,
+    /// a C header containing a standalone instruction length decoder. only the symbol prefix and the include guard
+    /// options apply to it.
+    LengthDecoder,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum IncludeGuard {
+    /// `#pragma once`
+    PragmaOnce,
+    /// an `#ifndef` guard named after the file
+    Ifndef,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum TablesMode {
+    /// the tables are defined in the tables header, which may only be included by a single translation unit
+    Header,
+    /// the tables are defined as `static const` in the tables header, so that it can be included anywhere
+    Static,
+    /// the tables are defined in a separate source file, and declared as `extern` in the tables header
+    Source,
+}
+
+// the options of the c output format. these are flattened next to `GenerateArgs` rather than into it, since clap can't
+// detect whether an optional flattened struct is present if it contains another flattened struct.
+#[derive(Args)]
+struct CCodeArgs {
+    /// a prefix for the names of all emitted symbols, so that multiple generated copies can live in a single program.
+    /// the prefix is uppercased for constants and macros.
+    #[arg(long, default_value = "")]
+    symbol_prefix: String,
+
+    /// the name of the header containing the table types
+    #[arg(long, default_value = "types.h")]
+    types_file_name: String,
+
+    /// the name of the header containing the tables
+    #[arg(long, default_value = "tables.h")]
+    tables_file_name: String,
+
+    #[arg(long, value_enum, default_value_t = IncludeGuard::PragmaOnce)]
+    include_guard: IncludeGuard,
+
+    /// where the tables are defined
+    #[arg(long, value_enum, default_value_t = TablesMode::Header)]
+    tables_mode: TablesMode,
+
+    /// the name of the source file containing the tables, if they are defined in a separate source file
+    #[arg(long, default_value = "tables.c")]
+    tables_source_file_name: String,
+
+    /// how the table types are laid out. the fields of the table types should be accessed using the generated accessor
+    /// macros, which work with every layout.
+    #[arg(long, value_enum, default_value_t = StructLayout::Packed)]
+    struct_layout: StructLayout,
+
+    #[arg(long, value_enum, default_value_t = CodeStyle::Compact)]
+    code_style: CodeStyle,
+
+    /// how the values of the variants of `mnemonic_t` are assigned
+    #[arg(long, value_enum, default_value_t = EnumOrder::Appearance)]
+    enum_order: EnumOrder,
+
+    /// the id registry file, which lists the names of the mnemonics in the order of their values. generation fails if
+    /// the value of any mnemonic listed in it would change. in the registry enum order, it is updated with the new
+    /// values, and in other orders it is only written when it is created.
+    #[arg(long)]
+    enum_registry: Option<PathBuf>,
+
+    /// create the id registry file if it does not exist, instead of failing
+    #[arg(long)]
+    create_enum_registry: bool,
+}
+impl Default for CCodeArgs {
+    fn default() -> Self {
+        Self {
+            symbol_prefix: String::new(),
+            types_file_name: "types.h".to_string(),
+            tables_file_name: "tables.h".to_string(),
+            include_guard: IncludeGuard::PragmaOnce,
+            tables_mode: TablesMode::Header,
+            tables_source_file_name: "tables.c".to_string(),
+            struct_layout: StructLayout::Packed,
+            code_style: CodeStyle::Compact,
+            enum_order: EnumOrder::Appearance,
+            enum_registry: None,
+            create_enum_registry: false,
+        }
+    }
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    output_dir: PathBuf,
+
+    #[arg(long, value_enum, default_value_t = OutputFormat::C)]
+    format: OutputFormat,
+
+    /// print the sizes of the generated tables and the savings of their deduplication, and warn about bit fields which
+    /// no longer fit in a byte. only supported for the c format.
+    #[arg(long)]
+    stats: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// generate the tables into the given directory
+    Generate {
+        #[command(flatten)]
+        args: GenerateArgs,
+
+        #[command(flatten)]
+        c_code: CCodeArgs,
+    },
+
+    /// convert a datafile in the XED format into rows of `insn_defs.toml`. instructions which are already defined in
+    /// the tables are skipped, and patterns which can't be represented or don't fit the tables are reported to stderr.
+    ImportXed {
+        input: PathBuf,
+
+        /// the file to write the rows to. defaults to stdout.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+
+    /// check the invariants of the tables, and report every violation along with its opcode address.
+    Validate,
+}
+
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Cli {
-    output_dir: PathBuf,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    generate: Option<GenerateArgs>,
+
+    #[command(flatten)]
+    c_code: CCodeArgs,
+}
+
+fn generate(args: GenerateArgs, c_code: &CCodeArgs) -> Result<(), String> {
+    if args.stats && !matches!(args.format, OutputFormat::C) {
+        return Err("--stats is only supported for the c format".to_string());
+    }
+    match args.format {
+        OutputFormat::C => {
+            let generated_code = generate_code(c_code)?;
+            if args.stats {
+                print!("{}", generated_code.stats.report());
+                for warning in generated_code.stats.warnings() {
+                    eprintln!("warning: {}", warning);
+                }
+            }
+            std::fs::write(
+                args.output_dir.join(&c_code.types_file_name),
+                generated_code.types_file.code(),
+            )
+            .unwrap();
+            std::fs::write(
+                args.output_dir.join(&c_code.tables_file_name),
+                generated_code.tables_file.code(),
+            )
+            .unwrap();
+            if let Some(tables_source_file) = &generated_code.tables_source_file {
+                std::fs::write(
+                    args.output_dir.join(&c_code.tables_source_file_name),
+                    tables_source_file.code(),
+                )
+                .unwrap();
+            }
+            if let (Some(path), Some(enum_registry)) =
+                (&c_code.enum_registry, &generated_code.enum_registry)
+            {
+                std::fs::write(path, enum_registry).unwrap();
+            }
+        }
+        OutputFormat::Json => {
+            std::fs::write(args.output_dir.join("tables.json"), generate_json()?).unwrap();
+        }
+        OutputFormat::LengthDecoder => {
+            let length_decoder = gen_length_decoder(
+                &gen_first_opcode_byte_table()?,
+                &gen_second_opcode_byte_table()?,
+                c_code,
+            );
+            std::fs::write(
+                args.output_dir.join(LENGTH_DECODER_FILE_NAME),
+                length_decoder.code(),
+            )
+            .unwrap();
+        }
+    }
+    Ok(())
+}
+
+fn import_xed_file(input: &Path, output: Option<&Path>) -> Result<(), String> {
+    let text = std::fs::read_to_string(input)
+        .map_err(|err| format!("failed to read {}: {}", input.display(), err))?;
+    let import = import_xed(&text).map_err(|err| format!("{}: {}", input.display(), err))?;
+    for (line, message) in &import.unrepresentable {
+        eprintln!("{}:{}: {}", input.display(), line, message);
+    }
+
+    let mut first_opcode_byte_table = gen_first_opcode_byte_table()?;
+    let mut second_opcode_byte_table = gen_second_opcode_byte_table()?;
+    let mut rows = format!("# imported from {}\n", input.display());
+    let mut skipped_amount = 0;
+    let mut rejected_amount = 0;
+    for insn_def in &import.insn_defs {
+        let table = if insn_def.map == MAP_PRIMARY {
+            &mut first_opcode_byte_table
+        } else {
+            &mut second_opcode_byte_table
+        };
+        match insn_def.try_apply(table) {
+            Ok(()) => {}
+            // the slot may already be defined either by the tables or by a previously imported row
+            Err(ApplyError::AlreadyDefined) => {
+                skipped_amount += 1;
+                continue;
+            }
+            Err(err @ ApplyError::Invalid(_)) => {
+                eprintln!("{}: {}: {}", input.display(), insn_def.location(), err);
+                rejected_amount += 1;
+                continue;
+            }
+        }
+        rows.push('\n');
+        rows.push_str(&insn_def.to_toml());
+    }
+    eprintln!(
+        "imported {} instructions, skipped {} already defined instructions, rejected {} invalid instructions, {} forms \
+         can't be represented",
+        import.insn_defs.len() - skipped_amount - rejected_amount,
+        skipped_amount,
+        rejected_amount,
+        import.unrepresentable.len()
+    );
+
+    match output {
+        Some(output) => std::fs::write(output, rows)
+            .map_err(|err| format!("failed to write {}: {}", output.display(), err))?,
+        None => print!("{}", rows),
+    }
+    Ok(())
+}
+
+fn validate() -> Result<(), String> {
+    let first_opcode_byte_table = gen_first_opcode_byte_table()?;
+    let second_opcode_byte_table = gen_second_opcode_byte_table()?;
+    let violations: Vec<String> = validate_table(MAP_PRIMARY, &first_opcode_byte_table)
+        .into_iter()
+        .chain(validate_table(MAP_0F, &second_opcode_byte_table))
+        .collect();
+    for violation in &violations {
+        println!("{}", violation);
+    }
+    if !violations.is_empty() {
+        eprintln!("found {} violations", violations.len());
+        std::process::exit(1);
+    }
+    println!("no violations found");
+    Ok(())
 }
 
 fn main() {
     let cli = Cli::parse();
-    let generated_code = generate_code();
-    std::fs::write(
-        cli.output_dir.join("types.h"),
-        generated_code.types_file.code(),
-    )
-    .unwrap();
-    std::fs::write(
-        cli.output_dir.join("tables.h"),
-        generated_code.tables_file.code(),
-    )
-    .unwrap();
+    let result = match (cli.command, cli.generate) {
+        (Some(Command::Generate { args, c_code }), _) => generate(args, &c_code),
+        (None, Some(args)) => generate(args, &cli.c_code),
+        (Some(Command::ImportXed { input, output }), _) => {
+            import_xed_file(&input, output.as_deref())
+        }
+        (Some(Command::Validate), _) => validate(),
+        (None, None) => {
+            Cli::command().print_help().unwrap();
+            std::process::exit(2);
+        }
+    };
+    if let Err(error) = result {
+        eprintln!("error: {}", error);
+        std::process::exit(1);
+    }
 }
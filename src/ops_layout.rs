@@ -0,0 +1,194 @@
+//! the layout of the operand lists in the laid out ops infos table.
+//!
+//! each instruction references its operands by the index of its first operand in the table and the amount of operands,
+//! so operand lists which overlap, for example `[RM_8]` and `[RM_8, IMM_8_NO_EXT]`, can share their storage. finding
+//! the shortest layout is the shortest common superstring problem, which is np-hard, so the layout is found using the
+//! usual greedy approximation, which repeatedly merges the two lists with the longest overlap.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::table_types::*;
+
+pub struct OpsLayout {
+    /// the entries of the laid out ops infos table.
+    pub ops_infos: Vec<OpInfo>,
+    first_op_indexes: HashMap<Ops, usize>,
+}
+impl OpsLayout {
+    /// the index in the laid out ops infos table of the first operand of the given operand list.
+    pub fn first_op_index(&self, ops: Ops) -> usize {
+        self.first_op_indexes[ops]
+    }
+}
+
+/// the chain which a piece was merged into. pieces are merged by linking the end of one chain to the start of another,
+/// so the chains are tracked with a union find.
+fn chain_of(chains: &mut [usize], piece: usize) -> usize {
+    let mut chain = piece;
+    while chains[chain] != chain {
+        chains[chain] = chains[chains[chain]];
+        chain = chains[chain];
+    }
+    chain
+}
+
+/// lays out the given unique operand lists so that every one of them appears in the result as a contiguous run.
+pub fn lay_out_ops(uniq_ops_infos: &[Ops]) -> OpsLayout {
+    // lists which are contained in other lists don't need any storage of their own. since the lists are unique, a list
+    // is contained in another one exactly if it is a proper part of it.
+    let proper_parts: HashSet<&[OpInfo]> = uniq_ops_infos
+        .iter()
+        .flat_map(|ops| (1..ops.len()).flat_map(move |len| ops.windows(len)))
+        .collect();
+    let pieces: Vec<Ops> = uniq_ops_infos
+        .iter()
+        .copied()
+        .filter(|ops| !ops.is_empty() && !proper_parts.contains(ops))
+        .collect();
+
+    // repeatedly merge the pair with the longest overlap. since no piece is contained in another one, the overlap of
+    // two merged chains is the overlap of the last piece of the first chain and the first piece of the second one, so
+    // the candidate overlaps are only computed once per length, by indexing the pieces by their prefixes. ties are
+    // broken by the order of the lists, to keep the layout deterministic. `next` is the piece which follows each piece
+    // in its chain, and the length of their overlap.
+    let mut next: Vec<Option<(usize, usize)>> = vec![None; pieces.len()];
+    let mut has_prev = vec![false; pieces.len()];
+    let mut chains: Vec<usize> = (0..pieces.len()).collect();
+    let max_len = pieces.iter().map(|piece| piece.len()).max().unwrap_or(0);
+    for len in (1..max_len).rev() {
+        let mut pieces_by_prefix: HashMap<&[OpInfo], Vec<usize>> = HashMap::new();
+        for (j, piece) in pieces.iter().enumerate() {
+            if piece.len() > len {
+                pieces_by_prefix.entry(&piece[..len]).or_default().push(j);
+            }
+        }
+        for (i, piece) in pieces.iter().enumerate() {
+            if piece.len() <= len || next[i].is_some() {
+                continue;
+            }
+            let Some(candidates) = pieces_by_prefix.get(&piece[piece.len() - len..]) else {
+                continue;
+            };
+            for &j in candidates {
+                if has_prev[j] || chain_of(&mut chains, i) == chain_of(&mut chains, j) {
+                    continue;
+                }
+                next[i] = Some((j, len));
+                has_prev[j] = true;
+                let chain = chain_of(&mut chains, j);
+                chains[chain] = chain_of(&mut chains, i);
+                break;
+            }
+        }
+    }
+
+    let mut ops_infos: Vec<OpInfo> = Vec::new();
+    for first in (0..pieces.len()).filter(|piece| !has_prev[*piece]) {
+        ops_infos.extend_from_slice(pieces[first]);
+        let mut piece = first;
+        while let Some((next_piece, overlap_len)) = next[piece] {
+            ops_infos.extend_from_slice(&pieces[next_piece][overlap_len..]);
+            piece = next_piece;
+        }
+    }
+
+    // every list is found at its first occurrence, which is looked up in an index of all runs of the layout.
+    let mut first_op_indexes_by_ops: HashMap<&[OpInfo], usize> = HashMap::new();
+    for len in 1..=max_len {
+        for (first_op_index, window) in ops_infos.windows(len).enumerate() {
+            first_op_indexes_by_ops
+                .entry(window)
+                .or_insert(first_op_index);
+        }
+    }
+    let first_op_indexes = uniq_ops_infos
+        .iter()
+        .map(|ops| {
+            let first_op_index = if ops.is_empty() {
+                0
+            } else {
+                first_op_indexes_by_ops[*ops]
+            };
+            (*ops, first_op_index)
+        })
+        .collect();
+    OpsLayout {
+        ops_infos,
+        first_op_indexes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// checks that every list is found at its first op index, and returns the layout.
+    fn check_layout(uniq_ops_infos: &[Ops]) -> Vec<OpInfo> {
+        let layout = lay_out_ops(uniq_ops_infos);
+        for ops in uniq_ops_infos {
+            let first_op_index = layout.first_op_index(ops);
+            assert_eq!(
+                &layout.ops_infos[first_op_index..first_op_index + ops.len()],
+                *ops
+            );
+        }
+        layout.ops_infos
+    }
+
+    #[test]
+    fn overlapping_lists_share_their_storage() {
+        // the suffix of the first list is the prefix of the second one
+        let ops_infos = check_layout(&[
+            &[OpInfo::RM_8, OpInfo::R_MODRM_8],
+            &[OpInfo::R_MODRM_8, OpInfo::RM_8],
+        ]);
+        assert_eq!(ops_infos, [OpInfo::RM_8, OpInfo::R_MODRM_8, OpInfo::RM_8]);
+
+        // the prefix of the first list is the suffix of the second one
+        let ops_infos = check_layout(&[
+            &[OpInfo::AL, OpInfo::IMM_8_NO_EXT],
+            &[OpInfo::RM_8, OpInfo::AL],
+        ]);
+        assert_eq!(ops_infos, [OpInfo::RM_8, OpInfo::AL, OpInfo::IMM_8_NO_EXT]);
+    }
+
+    #[test]
+    fn contained_lists_need_no_storage() {
+        let ops_infos = check_layout(&[
+            &[OpInfo::R_MODRM_8],
+            &[OpInfo::RM_8, OpInfo::R_MODRM_8, OpInfo::IMM_8_NO_EXT],
+            &[OpInfo::RM_8, OpInfo::R_MODRM_8],
+        ]);
+        assert_eq!(
+            ops_infos,
+            [OpInfo::RM_8, OpInfo::R_MODRM_8, OpInfo::IMM_8_NO_EXT]
+        );
+    }
+
+    #[test]
+    fn empty_list_is_at_index_0() {
+        let layout = lay_out_ops(&[&[], &[OpInfo::CL]]);
+        assert_eq!(layout.first_op_index(&[]), 0);
+        assert_eq!(layout.ops_infos, [OpInfo::CL]);
+
+        // the empty list needs an index even if there are no operands at all
+        assert_eq!(lay_out_ops(&[&[]]).first_op_index(&[]), 0);
+    }
+
+    #[test]
+    fn layout_is_shorter_than_concatenation() {
+        let uniq_ops_infos: &[Ops] = &[
+            &[OpInfo::RM_8, OpInfo::R_MODRM_8],
+            &[OpInfo::R_MODRM_8, OpInfo::RM_8],
+            &[OpInfo::AL, OpInfo::IMM_8_NO_EXT],
+            &[OpInfo::RM_8, OpInfo::IMM_8_NO_EXT],
+            &[OpInfo::IMM_8_NO_EXT],
+            &[OpInfo::CL, OpInfo::AL],
+        ];
+        let ops_infos = check_layout(uniq_ops_infos);
+        let concatenated_len: usize = uniq_ops_infos.iter().map(|ops| ops.len()).sum();
+        assert!(ops_infos.len() < concatenated_len);
+        // the greedy merges save one operand for each of the overlapping pairs, and all of the contained list
+        assert_eq!(ops_infos.len(), concatenated_len - 4);
+    }
+}
@@ -0,0 +1,109 @@
+// reads back every entry of the opcode byte tables through the emitted tables, and dumps a description of it, one line
+// per instruction. the format must match the one of `roundtrip_tests.rs`. the fields are only accessed through the
+// generated accessors, so that the harness works with every struct layout.
+#include <stdio.h>
+
+#include "types.h"
+#include "tables.h"
+
+static void dump_op_size_info(unsigned index) {
+    const op_size_info_t* op_size_info = &op_size_infos_table[index];
+    printf("%s/%s/%s/%s", op_size_names[OP_SIZE_INFO_WITH_OPERAND_SIZE_OVERRIDE(op_size_info)],
+           op_size_names[OP_SIZE_INFO_MODE_32(op_size_info)], op_size_names[OP_SIZE_INFO_MODE_64(op_size_info)],
+           op_size_names[OP_SIZE_INFO_MODE_64_WITH_REX_W(op_size_info)]);
+}
+
+static void dump_op(const op_info_t* op) {
+    printf("%s(", op_kind_names[OP_INFO_KIND(op)]);
+    switch (OP_INFO_KIND(op)) {
+    case OP_KIND_IMM:
+        dump_op_size_info(OP_INFO_IMM_ENCODED_SIZE_INFO_INDEX(op));
+        printf(",");
+        dump_op_size_info(OP_INFO_IMM_EXTENDED_SIZE_INFO_INDEX(op));
+        printf(",%s", OP_INFO_IMM_EXTEND_KIND(op) == IMM_EXT_KIND_SIGN_EXTEND ? "sign_extend" : "zero_extend");
+        break;
+    case OP_KIND_SPECIFIC_IMM:
+        dump_op_size_info(OP_INFO_SPECIFIC_IMM_OPERAND_SIZE_INFO_INDEX(op));
+        printf(",%s", specific_imm_names[OP_INFO_SPECIFIC_IMM_VALUE(op)]);
+        break;
+    case OP_KIND_REG:
+        dump_op_size_info(OP_INFO_REG_SIZE_INFO_INDEX(op));
+        printf(",%s", reg_encoding_names[OP_INFO_REG_ENCODING(op)]);
+        break;
+    case OP_KIND_RM:
+        dump_op_size_info(OP_INFO_RM_SIZE_INFO_INDEX(op));
+        break;
+    case OP_KIND_SPECIFIC_REG:
+        dump_op_size_info(OP_INFO_SPECIFIC_REG_SIZE_INFO_INDEX(op));
+        printf(",%s", specific_reg_names[OP_INFO_SPECIFIC_REG_REG(op)]);
+        break;
+    case OP_KIND_ZEXT_SPECIFIC_REG:
+        dump_op_size_info(OP_INFO_ZEXT_SPECIFIC_REG_SIZE_INFO_INDEX(op));
+        printf(",");
+        dump_op_size_info(OP_INFO_ZEXT_SPECIFIC_REG_EXTENDED_SIZE_INFO_INDEX(op));
+        printf(",%s", specific_reg_names[OP_INFO_ZEXT_SPECIFIC_REG_REG(op)]);
+        break;
+    case OP_KIND_ADDR_SIZE_SPECIFIC_REG:
+        printf("%s", specific_reg_names[OP_INFO_ADDR_SIZE_SPECIFIC_REG_REG(op)]);
+        break;
+    case OP_KIND_REL:
+        dump_op_size_info(OP_INFO_REL_SIZE_INFO_INDEX(op));
+        break;
+    case OP_KIND_MEM_OFFSET:
+        dump_op_size_info(OP_INFO_MEM_OFFSET_MEM_OPERAND_SIZE_INFO_INDEX(op));
+        break;
+    case OP_KIND_IMPLICIT:
+        dump_op_size_info(OP_INFO_IMPLICIT_SIZE_INFO_INDEX(op));
+        break;
+    case OP_KIND_COND:
+        break;
+    }
+    printf(")");
+}
+
+static void dump_regular_insn_info(const char* location, const regular_insn_info_t* insn) {
+    printf("%s: %s rep_kind=%s lockable=%u ops=[", location, mnemonic_names[REGULAR_INSN_INFO_MNEMONIC(insn)],
+           rep_kind_names[REGULAR_INSN_INFO_REP_KIND(insn)], (unsigned)REGULAR_INSN_INFO_LOCKABLE(insn));
+    for (unsigned i = 0; i < REGULAR_INSN_INFO_OPS_AMOUNT(insn); i++) {
+        if (i != 0) {
+            printf(",");
+        }
+        dump_op(&op_infos_table[laid_out_ops_infos_table[REGULAR_INSN_INFO_FIRST_OP_INDEX(insn) + i]]);
+    }
+    printf("]\n");
+}
+
+static void dump_opcode_byte_table(const char* map, const insn_info_t* table) {
+    char location[64];
+    for (unsigned opcode = 0; opcode < 0x100; opcode++) {
+        const insn_info_t* insn_info = &table[opcode];
+        snprintf(location, sizeof(location), "%s 0x%02x", map, opcode);
+        if (INSN_INFO_MNEMONIC(insn_info) == MNEMONIC_MODRM_REG_OPCODE_EXT) {
+            const modrm_reg_opcode_ext_table_t* modrm_reg_table =
+                &modrm_reg_opcode_ext_tables[INSN_INFO_MODRM_REG_OPCODE_EXT_MODRM_REG_TABLE_INDEX(insn_info)];
+            for (unsigned reg_value = 0; reg_value < 8; reg_value++) {
+                snprintf(location, sizeof(location), "%s 0x%02x /%u", map, opcode, reg_value);
+                dump_regular_insn_info(location, MODRM_REG_OPCODE_EXT_TABLE_BY_REG_VALUE(modrm_reg_table, reg_value));
+            }
+        } else if (INSN_INFO_MNEMONIC(insn_info) == MNEMONIC_REP_PREFIX_MODRM_EXT) {
+            const rep_prefix_modrm_ext_table_t* rep_prefix_modrm_table =
+                &rep_prefix_modrm_ext_tables[INSN_INFO_REP_PREFIX_MODRM_EXT_REP_PREFIX_MODRM_TABLE_INDEX(insn_info)];
+            dump_regular_insn_info(location, REP_PREFIX_MODRM_EXT_TABLE_DEFAULT_INSN(rep_prefix_modrm_table));
+            for (unsigned i = 0; i < REP_PREFIX_MODRM_EXT_TABLE_OVERRIDES_AMOUNT(rep_prefix_modrm_table); i++) {
+                const rep_prefix_modrm_override_t* rep_prefix_override =
+                    &rep_prefix_modrm_overrides_table[REP_PREFIX_MODRM_EXT_TABLE_FIRST_OVERRIDE_INDEX(rep_prefix_modrm_table) + i];
+                snprintf(location, sizeof(location), "%s 0x%02x with F3 prefix and modrm 0x%02x", map, opcode,
+                         (unsigned)REP_PREFIX_MODRM_OVERRIDE_MODRM(rep_prefix_override));
+                dump_regular_insn_info(location, REP_PREFIX_MODRM_OVERRIDE_INSN(rep_prefix_override));
+            }
+        } else {
+            dump_regular_insn_info(location, INSN_INFO_REGULAR(insn_info));
+        }
+    }
+}
+
+int main(void) {
+    dump_opcode_byte_table("primary", first_opcode_byte_table);
+    dump_opcode_byte_table("0f", second_opcode_byte_table);
+    return 0;
+}
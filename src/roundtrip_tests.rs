@@ -0,0 +1,168 @@
+//! round trip tests from the table model through the emitted c tables.
+//!
+//! the emitter packs the tables by deduplicating operands, operand sizes and extension tables, and referencing them by
+//! index. to make sure that this packing is lossless, a c harness reads back every entry of the opcode byte tables
+//! through the emitted tables and dumps a description of it, which is compared with the same description produced
+//! directly from the model.
+
+use to_snake_case::ToSnakeCase;
+
+use crate::{
+    c_emitter::{CodeStyle, StructLayout},
+    c_test_utils::compile_and_run,
+    first_opcode_byte_table::gen_first_opcode_byte_table,
+    insn_defs::{MAP_0F, MAP_PRIMARY},
+    second_opcode_byte_table::gen_second_opcode_byte_table,
+    table_types::*,
+    CCodeArgs,
+};
+
+const ROUNDTRIP_HARNESS_C: &str = include_str!("roundtrip_harness.c");
+
+fn variant_name<'a, T>(value: &'a T) -> String
+where
+    &'a T: Into<&'static str>,
+{
+    value.into().to_snake_case()
+}
+
+fn dump_op_size_info(op_size_info: &OpSizeInfo) -> String {
+    format!(
+        "{}/{}/{}/{}",
+        op_size_info.with_operand_size_override as u32,
+        op_size_info.mode_32 as u32,
+        op_size_info.mode_64 as u32,
+        op_size_info.mode_64_with_rex_w as u32
+    )
+}
+
+fn dump_op(op: &OpInfo) -> String {
+    let args = match op {
+        OpInfo::Imm(imm) => format!(
+            "{},{},{}",
+            dump_op_size_info(&imm.encoded_size),
+            dump_op_size_info(&imm.extended_size),
+            variant_name(&imm.extend_kind)
+        ),
+        OpInfo::SpecificImm(specific_imm) => format!(
+            "{},{}",
+            dump_op_size_info(&specific_imm.operand_size),
+            variant_name(&specific_imm.value)
+        ),
+        OpInfo::Reg(reg) => format!(
+            "{},{}",
+            dump_op_size_info(&reg.size),
+            variant_name(&reg.encoding)
+        ),
+        OpInfo::Rm(size) | OpInfo::Rel(size) | OpInfo::Implicit(size) => dump_op_size_info(size),
+        OpInfo::SpecificReg(specific_reg) => format!(
+            "{},{}",
+            dump_op_size_info(&specific_reg.size),
+            variant_name(&specific_reg.reg)
+        ),
+        OpInfo::ZextSpecificReg(zext_specific_reg) => format!(
+            "{},{},{}",
+            dump_op_size_info(&zext_specific_reg.size),
+            dump_op_size_info(&zext_specific_reg.extended_size),
+            variant_name(&zext_specific_reg.reg)
+        ),
+        OpInfo::AddrSizeSpecificReg(reg) => variant_name(reg),
+        OpInfo::MemOffset(mem_offset) => dump_op_size_info(&mem_offset.mem_operand_size),
+        OpInfo::Cond => String::new(),
+    };
+    format!("{}({})", variant_name(op), args)
+}
+
+fn dump_regular_insn_info(dump: &mut String, location: &str, insn: &RegularInsnInfo) {
+    let ops: Vec<String> = insn.ops.iter().map(dump_op).collect();
+    dump.push_str(&format!(
+        "{}: {} rep_kind={} lockable={} ops=[{}]\n",
+        location,
+        insn.mnemonic,
+        variant_name(&insn.rep_kind),
+        insn.lockable as u32,
+        ops.join(",")
+    ));
+}
+
+fn dump_opcode_byte_table(dump: &mut String, map: &str, table: &[InsnInfo]) {
+    for (opcode, insn_info) in table.iter().enumerate() {
+        let location = format!("{} {:#04x}", map, opcode);
+        match insn_info {
+            InsnInfo::Regular(insn) => dump_regular_insn_info(dump, &location, insn),
+            InsnInfo::ModrmRegOpcodeExt(modrm_reg_opcode_ext) => {
+                for (reg_value, insn) in modrm_reg_opcode_ext.by_reg_value.iter().enumerate() {
+                    dump_regular_insn_info(dump, &format!("{} /{}", location, reg_value), insn);
+                }
+            }
+            InsnInfo::RepPrefixModrmExt(rep_prefix_modrm_ext) => {
+                dump_regular_insn_info(dump, &location, &rep_prefix_modrm_ext.default);
+                for rep_prefix_override in rep_prefix_modrm_ext.overrides {
+                    dump_regular_insn_info(
+                        dump,
+                        &format!(
+                            "{} with F3 prefix and modrm {:#04x}",
+                            location, rep_prefix_override.modrm
+                        ),
+                        &rep_prefix_override.insn,
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn check_emitted_tables_roundtrip(name: &str, args: CCodeArgs) {
+    let mut expected = String::new();
+    dump_opcode_byte_table(
+        &mut expected,
+        MAP_PRIMARY,
+        &gen_first_opcode_byte_table().unwrap(),
+    );
+    dump_opcode_byte_table(
+        &mut expected,
+        MAP_0F,
+        &gen_second_opcode_byte_table().unwrap(),
+    );
+
+    let dump = compile_and_run(&[args], name, ROUNDTRIP_HARNESS_C);
+
+    for (line, expected_line) in dump.lines().zip(expected.lines()) {
+        assert_eq!(
+            line, expected_line,
+            "the entry read back from the emitted tables differs from the model"
+        );
+    }
+    assert_eq!(
+        dump.lines().count(),
+        expected.lines().count(),
+        "the amount of entries read back from the emitted tables differs from the model"
+    );
+}
+
+#[test]
+fn emitted_tables_roundtrip() {
+    check_emitted_tables_roundtrip("roundtrip_harness", CCodeArgs::default());
+}
+
+#[test]
+fn emitted_tables_roundtrip_in_portable_layout() {
+    check_emitted_tables_roundtrip(
+        "portable_roundtrip_harness",
+        CCodeArgs {
+            struct_layout: StructLayout::Portable,
+            ..CCodeArgs::default()
+        },
+    );
+}
+
+#[test]
+fn emitted_tables_roundtrip_in_pretty_style() {
+    check_emitted_tables_roundtrip(
+        "pretty_roundtrip_harness",
+        CCodeArgs {
+            code_style: CodeStyle::Pretty,
+            ..CCodeArgs::default()
+        },
+    );
+}
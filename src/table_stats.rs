@@ -0,0 +1,112 @@
+//! a report of the sizes of the generated tables, and of the savings of their deduplication.
+
+use crate::c_emitter::{BitFieldStats, TableStats};
+
+/// a deduplication layer of the generated tables, which replaces repeated entries with references to a single copy.
+pub struct DedupStats {
+    pub name: &'static str,
+    /// the amount of entries which would have been emitted without deduplication.
+    pub entries_before: usize,
+    pub entries_after: usize,
+    /// the size in bytes of each entry.
+    pub entry_size: usize,
+}
+impl DedupStats {
+    /// the size in bytes of the entries which were deduplicated away. the size of the references which replace them is
+    /// not taken into account.
+    pub fn saved_size(&self) -> usize {
+        (self.entries_before - self.entries_after) * self.entry_size
+    }
+}
+
+pub struct TablesStats {
+    pub tables: Vec<TableStats>,
+    pub bit_fields: Vec<BitFieldStats>,
+    pub dedup_layers: Vec<DedupStats>,
+}
+impl TablesStats {
+    pub fn total_size(&self) -> usize {
+        self.tables.iter().map(|table| table.size).sum()
+    }
+
+    /// warnings about bit fields which no longer fit in a byte, since they are declared with a wider integer type, which
+    /// usually grows the types containing them.
+    pub fn warnings(&self) -> Vec<String> {
+        self.bit_fields
+            .iter()
+            .filter(|bit_field| bit_field.bits > 8)
+            .map(|bit_field| {
+                format!(
+                    "{}.{} is {} bits wide, so it no longer fits in a uint8_t",
+                    bit_field.type_name, bit_field.path, bit_field.bits
+                )
+            })
+            .collect()
+    }
+
+    pub fn report(&self) -> String {
+        let mut report = String::from("tables:\n");
+        for table in &self.tables {
+            report.push_str(&format!(
+                "  {:<40} {:>5} entries {:>7} bytes\n",
+                table.name, table.entries, table.size
+            ));
+        }
+        report.push_str(&format!(
+            "  {:<40} {:>21} bytes\n",
+            "total",
+            self.total_size()
+        ));
+
+        report.push_str("bit fields:\n");
+        for bit_field in &self.bit_fields {
+            report.push_str(&format!(
+                "  {:<60} {:>2} bits\n",
+                format!("{}.{}", bit_field.type_name, bit_field.path),
+                bit_field.bits
+            ));
+        }
+
+        report.push_str("deduplication:\n");
+        for dedup_layer in &self.dedup_layers {
+            report.push_str(&format!(
+                "  {:<40} {:>5} -> {:>5} entries, saved {:>7} bytes\n",
+                dedup_layer.name,
+                dedup_layer.entries_before,
+                dedup_layer.entries_after,
+                dedup_layer.saved_size()
+            ));
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate_code, CCodeArgs, TablesMode};
+
+    /// the stats cover every emitted table, and deduplication never grows a table.
+    #[test]
+    fn stats_cover_every_emitted_table() {
+        let args = CCodeArgs {
+            tables_mode: TablesMode::Source,
+            ..CCodeArgs::default()
+        };
+        let generated_code = generate_code(&args).unwrap();
+        let declarations_amount = generated_code
+            .tables_file
+            .code()
+            .lines()
+            .filter(|line| line.starts_with("extern const "))
+            .count();
+        assert_eq!(declarations_amount, generated_code.stats.tables.len());
+        for dedup_layer in &generated_code.stats.dedup_layers {
+            assert!(
+                dedup_layer.entries_after <= dedup_layer.entries_before,
+                "{}",
+                dedup_layer.name
+            );
+        }
+        assert!(generated_code.stats.warnings().is_empty());
+    }
+}
@@ -1,4 +1,5 @@
 use delve::{EnumDisplay, EnumToStr, EnumVariantNames};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 pub type Mnemonic = &'static str;
 
@@ -11,112 +12,178 @@ pub enum OpSize {
     S32 = 32,
     S64 = 64,
 }
+impl Serialize for OpSize {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(*self as u32)
+    }
+}
+impl<'de> Deserialize<'de> for OpSize {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match u32::deserialize(deserializer)? {
+            8 => Ok(Self::S8),
+            16 => Ok(Self::S16),
+            32 => Ok(Self::S32),
+            64 => Ok(Self::S64),
+            other => Err(serde::de::Error::custom(format!(
+                "invalid operand size {}",
+                other
+            ))),
+        }
+    }
+}
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// declares the constants of a type, along with the `NAMED` list of their names and values, so that the instruction
+/// definitions can refer to them by name.
+macro_rules! named_consts {
+    (impl $ty:ident { $($(#[$attr:meta])* pub const $name:ident: Self = $value:expr;)* }) => {
+        impl $ty {
+            $($(#[$attr])* pub const $name: Self = $value;)*
+
+            /// the names and values of all the constants above.
+            pub const NAMED: &'static [(&'static str, Self)] = &[$((stringify!($name), Self::$name)),*];
+        }
+    };
+}
+
+/// the size of an operand in each mode. it is deserialized by `insn_defs`, which also accepts the names of the
+/// constants.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct OpSizeInfo {
     pub with_operand_size_override: OpSize,
     pub mode_32: OpSize,
     pub mode_64: OpSize,
     pub mode_64_with_rex_w: OpSize,
 }
-impl OpSizeInfo {
-    /// operand size is always 8 bits
-    pub const SZ_ALWAYS_8: Self = Self {
-        with_operand_size_override: OpSize::S8,
-        mode_32: OpSize::S8,
-        mode_64: OpSize::S8,
-        mode_64_with_rex_w: OpSize::S8,
-    };
-
-    /// operand size is always 16 bits
-    pub const SZ_ALWAYS_16: Self = Self {
-        with_operand_size_override: OpSize::S16,
-        mode_32: OpSize::S16,
-        mode_64: OpSize::S16,
-        mode_64_with_rex_w: OpSize::S16,
-    };
-
-    /// the default operand size for instructions that default to 32-bit operands.
-    pub const SZ_16_32_64_DEF_32: Self = Self {
-        with_operand_size_override: OpSize::S16,
-        mode_32: OpSize::S32,
-        mode_64: OpSize::S32,
-        mode_64_with_rex_w: OpSize::S64,
-    };
-
-    /// the default operand size for instructions that default to 64-bit operands.
-    pub const SZ_16_32_64_DEF_64: Self = Self {
-        with_operand_size_override: OpSize::S16,
-        mode_32: OpSize::S32,
-        mode_64: OpSize::S64,
-        mode_64_with_rex_w: OpSize::S64,
-    };
-
-    /// a common size info for immediate encodings that are either 16 or 32 bits.
-    pub const SZ_IMM_ENCODING_16_32: Self = Self {
-        with_operand_size_override: OpSize::S16,
-        mode_32: OpSize::S32,
-        mode_64: OpSize::S32,
-        mode_64_with_rex_w: OpSize::S32,
-    };
+named_consts! {
+    impl OpSizeInfo {
+        /// operand size is always 8 bits
+        pub const SZ_ALWAYS_8: Self = Self {
+            with_operand_size_override: OpSize::S8,
+            mode_32: OpSize::S8,
+            mode_64: OpSize::S8,
+            mode_64_with_rex_w: OpSize::S8,
+        };
+
+        /// operand size is always 16 bits
+        pub const SZ_ALWAYS_16: Self = Self {
+            with_operand_size_override: OpSize::S16,
+            mode_32: OpSize::S16,
+            mode_64: OpSize::S16,
+            mode_64_with_rex_w: OpSize::S16,
+        };
+
+        /// operand size is always 32 bits
+        pub const SZ_ALWAYS_32: Self = Self {
+            with_operand_size_override: OpSize::S32,
+            mode_32: OpSize::S32,
+            mode_64: OpSize::S32,
+            mode_64_with_rex_w: OpSize::S32,
+        };
+
+        /// operand size is always 64 bits
+        pub const SZ_ALWAYS_64: Self = Self {
+            with_operand_size_override: OpSize::S64,
+            mode_32: OpSize::S64,
+            mode_64: OpSize::S64,
+            mode_64_with_rex_w: OpSize::S64,
+        };
+
+        /// the default operand size for instructions that default to 32-bit operands.
+        pub const SZ_16_32_64_DEF_32: Self = Self {
+            with_operand_size_override: OpSize::S16,
+            mode_32: OpSize::S32,
+            mode_64: OpSize::S32,
+            mode_64_with_rex_w: OpSize::S64,
+        };
+
+        /// the default operand size for instructions that default to 64-bit operands.
+        pub const SZ_16_32_64_DEF_64: Self = Self {
+            with_operand_size_override: OpSize::S16,
+            mode_32: OpSize::S32,
+            mode_64: OpSize::S64,
+            mode_64_with_rex_w: OpSize::S64,
+        };
+
+        /// a common size info for immediate encodings that are either 16 or 32 bits.
+        pub const SZ_IMM_ENCODING_16_32: Self = Self {
+            with_operand_size_override: OpSize::S16,
+            mode_32: OpSize::S32,
+            mode_64: OpSize::S32,
+            mode_64_with_rex_w: OpSize::S32,
+        };
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumVariantNames, EnumToStr)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, EnumVariantNames, EnumToStr, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
 pub enum ImmExtendKind {
     SignExtend,
     ZeroExtend,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ImmOpInfo {
     pub encoded_size: OpSizeInfo,
     pub extended_size: OpSizeInfo,
     pub extend_kind: ImmExtendKind,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumVariantNames, EnumToStr)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, EnumVariantNames, EnumToStr, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
 pub enum SpecificImm {
     Zero,
     One,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct SpecificImmOpInfo {
     pub value: SpecificImm,
     pub operand_size: OpSizeInfo,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct MemOffsetOpInfo {
     pub mem_operand_size: OpSizeInfo,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumVariantNames, EnumToStr)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, EnumVariantNames, EnumToStr, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
 pub enum RegEncoding {
     Modrm,
     Opcode,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct RegOpInfo {
     pub encoding: RegEncoding,
     pub size: OpSizeInfo,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumVariantNames, EnumToStr)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, EnumVariantNames, EnumToStr, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
 pub enum SpecificReg {
     Rax,
     Rdx,
     Rcx,
+    Rbx,
+    R11,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct SpecificRegOpInfo {
     pub reg: SpecificReg,
     pub size: OpSizeInfo,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ZextSpecificRegOpInfo {
     pub reg: SpecificReg,
     pub size: OpSizeInfo,
@@ -128,7 +195,19 @@ pub struct RelOpInfo {
     pub size: OpSizeInfo,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumDisplay, EnumVariantNames, EnumToStr)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumDisplay,
+    EnumVariantNames,
+    EnumToStr,
+    Serialize,
+    Deserialize,
+)]
+#[serde(tag = "kind", content = "info", rename_all = "snake_case")]
 pub enum OpInfo {
     /// immediate operand
     Imm(ImmOpInfo),
@@ -148,6 +227,10 @@ pub enum OpInfo {
     /// zero extended specific register which is enforced by the opcode
     ZextSpecificReg(ZextSpecificRegOpInfo),
 
+    /// specific register which is enforced by the opcode, and whose size is the address size rather than the
+    /// operand size, for example the counter register used by `loop`.
+    AddrSizeSpecificReg(SpecificReg),
+
     /// relative offset used for relative jumps
     Rel(OpSizeInfo),
 
@@ -159,96 +242,220 @@ pub enum OpInfo {
 
     Cond,
 }
-impl OpInfo {
-    pub const RM_8: Self = Self::Rm(OpSizeInfo::SZ_ALWAYS_8);
-    pub const RM_16_32_64_DEF_32: Self = Self::Rm(OpSizeInfo::SZ_16_32_64_DEF_32);
-    pub const RM_16_32_64_DEF_64: Self = Self::Rm(OpSizeInfo::SZ_16_32_64_DEF_64);
-    pub const R_MODRM_8: Self = Self::Reg(RegOpInfo {
-        encoding: RegEncoding::Modrm,
-        size: OpSizeInfo::SZ_ALWAYS_8,
-    });
-    pub const R_MODRM_16_32_64_DEF_32: Self = Self::Reg(RegOpInfo {
-        encoding: RegEncoding::Modrm,
-        size: OpSizeInfo::SZ_16_32_64_DEF_32,
-    });
-    pub const R_OPCODE_8: Self = Self::Reg(RegOpInfo {
-        encoding: RegEncoding::Opcode,
-        size: OpSizeInfo::SZ_ALWAYS_8,
-    });
-    pub const R_OPCODE_16_32_64_DEF_32: Self = Self::Reg(RegOpInfo {
-        encoding: RegEncoding::Opcode,
-        size: OpSizeInfo::SZ_16_32_64_DEF_32,
-    });
-    pub const R_OPCODE_16_32_64_DEF_64: Self = Self::Reg(RegOpInfo {
-        encoding: RegEncoding::Opcode,
-        size: OpSizeInfo::SZ_16_32_64_DEF_64,
-    });
-    pub const AL: Self = Self::SpecificReg(SpecificRegOpInfo {
-        size: OpSizeInfo::SZ_ALWAYS_8,
-        reg: SpecificReg::Rax,
-    });
-    pub const AX_16_32_64_DEF_32: Self = Self::SpecificReg(SpecificRegOpInfo {
-        size: OpSizeInfo::SZ_16_32_64_DEF_32,
-        reg: SpecificReg::Rax,
-    });
-    pub const DX_16_32_64_DEF_32: Self = Self::SpecificReg(SpecificRegOpInfo {
-        size: OpSizeInfo::SZ_16_32_64_DEF_32,
-        reg: SpecificReg::Rdx,
-    });
-    pub const CL: Self = Self::SpecificReg(SpecificRegOpInfo {
-        size: OpSizeInfo::SZ_ALWAYS_8,
-        reg: SpecificReg::Rcx,
-    });
-
-    /// an 8-bit immediate which should not be sign/zero extended.
-    pub const IMM_8_NO_EXT: Self = Self::Imm(ImmOpInfo {
-        encoded_size: OpSizeInfo::SZ_ALWAYS_8,
-        extended_size: OpSizeInfo::SZ_ALWAYS_8,
-        // doesn't matter
-        extend_kind: ImmExtendKind::SignExtend,
-    });
-
-    /// a 32 bit relative offset
-    pub const REL_32: Self = Self::Rel(OpSizeInfo {
-        // operand size override is not supported with relative operands, so this is ignored anyway
-        with_operand_size_override: OpSize::S16,
-        mode_32: OpSize::S32,
-        mode_64: OpSize::S32,
-        mode_64_with_rex_w: OpSize::S32,
-    });
+named_consts! {
+    impl OpInfo {
+        pub const RM_8: Self = Self::Rm(OpSizeInfo::SZ_ALWAYS_8);
+        pub const RM_16_32_64_DEF_32: Self = Self::Rm(OpSizeInfo::SZ_16_32_64_DEF_32);
+        pub const RM_16_32_64_DEF_64: Self = Self::Rm(OpSizeInfo::SZ_16_32_64_DEF_64);
+        pub const R_MODRM_8: Self = Self::Reg(RegOpInfo {
+            encoding: RegEncoding::Modrm,
+            size: OpSizeInfo::SZ_ALWAYS_8,
+        });
+        pub const R_MODRM_16_32_64_DEF_32: Self = Self::Reg(RegOpInfo {
+            encoding: RegEncoding::Modrm,
+            size: OpSizeInfo::SZ_16_32_64_DEF_32,
+        });
+        pub const R_OPCODE_8: Self = Self::Reg(RegOpInfo {
+            encoding: RegEncoding::Opcode,
+            size: OpSizeInfo::SZ_ALWAYS_8,
+        });
+        pub const R_OPCODE_16_32_64_DEF_32: Self = Self::Reg(RegOpInfo {
+            encoding: RegEncoding::Opcode,
+            size: OpSizeInfo::SZ_16_32_64_DEF_32,
+        });
+        pub const R_OPCODE_16_32_64_DEF_64: Self = Self::Reg(RegOpInfo {
+            encoding: RegEncoding::Opcode,
+            size: OpSizeInfo::SZ_16_32_64_DEF_64,
+        });
+        pub const AL: Self = Self::SpecificReg(SpecificRegOpInfo {
+            size: OpSizeInfo::SZ_ALWAYS_8,
+            reg: SpecificReg::Rax,
+        });
+        pub const AX_16_32_64_DEF_32: Self = Self::SpecificReg(SpecificRegOpInfo {
+            size: OpSizeInfo::SZ_16_32_64_DEF_32,
+            reg: SpecificReg::Rax,
+        });
+        pub const DX_16_32_64_DEF_32: Self = Self::SpecificReg(SpecificRegOpInfo {
+            size: OpSizeInfo::SZ_16_32_64_DEF_32,
+            reg: SpecificReg::Rdx,
+        });
+        pub const AX_16_32: Self = Self::SpecificReg(SpecificRegOpInfo {
+            size: OpSizeInfo::SZ_IMM_ENCODING_16_32,
+            reg: SpecificReg::Rax,
+        });
+        pub const DX_16: Self = Self::SpecificReg(SpecificRegOpInfo {
+            size: OpSizeInfo::SZ_ALWAYS_16,
+            reg: SpecificReg::Rdx,
+        });
+        pub const CL: Self = Self::SpecificReg(SpecificRegOpInfo {
+            size: OpSizeInfo::SZ_ALWAYS_8,
+            reg: SpecificReg::Rcx,
+        });
+        pub const EAX: Self = Self::SpecificReg(SpecificRegOpInfo {
+            size: OpSizeInfo::SZ_ALWAYS_32,
+            reg: SpecificReg::Rax,
+        });
+        pub const EBX: Self = Self::SpecificReg(SpecificRegOpInfo {
+            size: OpSizeInfo::SZ_ALWAYS_32,
+            reg: SpecificReg::Rbx,
+        });
+        pub const ECX: Self = Self::SpecificReg(SpecificRegOpInfo {
+            size: OpSizeInfo::SZ_ALWAYS_32,
+            reg: SpecificReg::Rcx,
+        });
+        pub const EDX: Self = Self::SpecificReg(SpecificRegOpInfo {
+            size: OpSizeInfo::SZ_ALWAYS_32,
+            reg: SpecificReg::Rdx,
+        });
+        pub const RCX: Self = Self::SpecificReg(SpecificRegOpInfo {
+            size: OpSizeInfo::SZ_ALWAYS_64,
+            reg: SpecificReg::Rcx,
+        });
+        pub const R11: Self = Self::SpecificReg(SpecificRegOpInfo {
+            size: OpSizeInfo::SZ_ALWAYS_64,
+            reg: SpecificReg::R11,
+        });
+
+        /// an 8-bit immediate which should not be sign/zero extended.
+        pub const IMM_8_NO_EXT: Self = Self::Imm(ImmOpInfo {
+            encoded_size: OpSizeInfo::SZ_ALWAYS_8,
+            extended_size: OpSizeInfo::SZ_ALWAYS_8,
+            // doesn't matter
+            extend_kind: ImmExtendKind::SignExtend,
+        });
+
+        /// a 16-bit immediate which should not be sign/zero extended.
+        pub const IMM_16_NO_EXT: Self = Self::Imm(ImmOpInfo {
+            encoded_size: OpSizeInfo::SZ_ALWAYS_16,
+            extended_size: OpSizeInfo::SZ_ALWAYS_16,
+            // doesn't matter
+            extend_kind: ImmExtendKind::ZeroExtend,
+        });
+
+        /// the counter register used by `loop` and `jrcxz`, sized according to the address size.
+        pub const ADDR_SIZE_CX: Self = Self::AddrSizeSpecificReg(SpecificReg::Rcx);
+
+        /// an 8 bit relative offset
+        pub const REL_8: Self = Self::Rel(OpSizeInfo::SZ_ALWAYS_8);
+
+        /// a 32 bit relative offset
+        pub const REL_32: Self = Self::Rel(OpSizeInfo {
+            // operand size override is not supported with relative operands, so this is ignored anyway
+            with_operand_size_override: OpSize::S16,
+            mode_32: OpSize::S32,
+            mode_64: OpSize::S32,
+            mode_64_with_rex_w: OpSize::S32,
+        });
+    }
 }
 
 pub type Ops = &'static [OpInfo];
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, Hash, EnumVariantNames, EnumToStr, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum RepKind {
+    /// REP prefixes have no meaning for this instruction
+    #[default]
+    None,
+
+    /// an F3 prefix means `rep`
+    Rep,
+
+    /// an F3 prefix means `repe`/`repz` and an F2 prefix means `repne`/`repnz`
+    RepeRepne,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct RegularInsnInfo {
     pub mnemonic: Mnemonic,
     pub ops: Ops,
+    pub rep_kind: RepKind,
+    /// whether a LOCK prefix is legal when the rm operand is a memory operand. a LOCK prefix is never legal when the
+    /// rm operand is a register, and is never legal at all for instructions which are not lockable.
+    pub lockable: bool,
 }
 impl RegularInsnInfo {
     pub const UNSUPPORTED: Self = Self {
         mnemonic: MNEMONIC_UNSUPPORTED,
         ops: &[],
+        rep_kind: RepKind::None,
+        lockable: false,
     };
+
+    /// whether this instruction is unsupported. unsupported entries of groups may still have the operands of the rest
+    /// of the group, so only the mnemonic is checked.
+    pub fn is_unsupported(&self) -> bool {
+        self.mnemonic == MNEMONIC_UNSUPPORTED
+    }
+
+    /// whether any of the operands is encoded in the modrm byte.
+    pub fn uses_modrm(&self) -> bool {
+        self.ops.iter().any(|op| {
+            matches!(
+                op,
+                OpInfo::Rm(_)
+                    | OpInfo::Reg(RegOpInfo {
+                        encoding: RegEncoding::Modrm,
+                        ..
+                    })
+            )
+        })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ModrmRegOpcodeExtInsnInfo {
     pub by_reg_value: [RegularInsnInfo; 8],
 }
-impl ModrmRegOpcodeExtInsnInfo {
-    pub fn new_with_same_operands(ops: Ops, mnemonics: [Mnemonic; 8]) -> Self {
-        Self {
-            by_reg_value: std::array::from_fn(|i| RegularInsnInfo {
-                mnemonic: mnemonics[i],
-                ops,
-            }),
-        }
-    }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RepPrefixModrmOverride {
+    pub modrm: u8,
+    pub insn: RegularInsnInfo,
+}
+
+/// an instruction whose identity changes when it has an F3 prefix and a specific modrm byte. this is used in the hint
+/// nop space, where for example `F3 0F 1E FA` is `endbr64`, while `0F 1E FA` is just a nop.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RepPrefixModrmExtInsnInfo {
+    /// the instruction used when there is no F3 prefix, or when no override matches the modrm byte.
+    pub default: RegularInsnInfo,
+    pub overrides: &'static [RepPrefixModrmOverride],
 }
 
 #[derive(Debug, Clone)]
 pub enum InsnInfo {
     Regular(RegularInsnInfo),
     ModrmRegOpcodeExt(ModrmRegOpcodeExtInsnInfo),
+    RepPrefixModrmExt(RepPrefixModrmExtInsnInfo),
+}
+impl InsnInfo {
+    /// whether the opcode is followed by a modrm byte. this is derived from the operands, so it is meaningless for
+    /// unsupported opcodes.
+    pub fn has_modrm(&self) -> bool {
+        match self {
+            InsnInfo::Regular(insn) => insn.uses_modrm(),
+            InsnInfo::ModrmRegOpcodeExt(_) | InsnInfo::RepPrefixModrmExt(_) => true,
+        }
+    }
+
+    /// whether any instruction of the opcode is supported.
+    pub fn is_supported(&self) -> bool {
+        match self {
+            InsnInfo::Regular(insn) => !insn.is_unsupported(),
+            InsnInfo::ModrmRegOpcodeExt(modrm_reg_opcode_ext) => modrm_reg_opcode_ext
+                .by_reg_value
+                .iter()
+                .any(|insn| !insn.is_unsupported()),
+            InsnInfo::RepPrefixModrmExt(rep_prefix_modrm_ext) => {
+                !rep_prefix_modrm_ext.default.is_unsupported()
+                    || rep_prefix_modrm_ext
+                        .overrides
+                        .iter()
+                        .any(|rep_prefix_override| !rep_prefix_override.insn.is_unsupported())
+            }
+        }
+    }
 }
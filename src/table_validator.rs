@@ -0,0 +1,354 @@
+//! consistency checks over the generated opcode byte tables.
+//!
+//! the tables are built by hand and by importing instruction definitions, so these checks compare them against
+//! reference facts about the encoding of each opcode map, which are independent of the instruction definitions.
+
+use crate::{insn_defs::*, table_types::*};
+
+/// whether the given opcode is followed by a modrm byte, according to the x86 reference.
+fn reference_has_modrm(map: &str, opcode: u8) -> bool {
+    match map {
+        MAP_PRIMARY => {
+            matches!(
+                opcode,
+                0x00..=0x3f if opcode & 0b111 < 4
+            ) || matches!(
+                opcode,
+                0x62 | 0x63
+                    | 0x69
+                    | 0x6b
+                    | 0x80..=0x8f
+                    | 0xc0
+                    | 0xc1
+                    | 0xc4..=0xc7
+                    | 0xd0..=0xd3
+                    | 0xd8..=0xdf
+                    | 0xf6
+                    | 0xf7
+                    | 0xfe
+                    | 0xff
+            )
+        }
+        MAP_0F => matches!(
+            opcode,
+            0x00..=0x03
+                | 0x0d
+                | 0x0f..=0x1f
+                | 0x20..=0x23
+                | 0x28..=0x2f
+                | 0x40..=0x76
+                | 0x78..=0x7f
+                | 0x90..=0x9f
+                | 0xa3..=0xa5
+                | 0xab..=0xaf
+                | 0xb0..=0xb7
+                | 0xb8..=0xbf
+                | 0xc0..=0xc7
+                | 0xd0..=0xff
+        ),
+        _ => unreachable!(),
+    }
+}
+
+/// whether the low 3 bits of the given opcode encode a register operand, according to the x86 reference.
+fn reference_has_opcode_reg(map: &str, opcode: u8) -> bool {
+    match map {
+        MAP_PRIMARY => matches!(opcode, 0x40..=0x5f | 0x90..=0x97 | 0xb0..=0xbf),
+        MAP_0F => matches!(opcode, 0xc8..=0xcf),
+        _ => unreachable!(),
+    }
+}
+
+/// whether the low 4 bits of the given opcode encode a condition, according to the x86 reference.
+fn reference_is_conditional(map: &str, opcode: u8) -> bool {
+    match map {
+        MAP_PRIMARY => matches!(opcode, 0x70..=0x7f),
+        MAP_0F => matches!(opcode, 0x40..=0x4f | 0x80..=0x9f),
+        _ => unreachable!(),
+    }
+}
+
+fn insn_has_rm_operand(insn: &RegularInsnInfo) -> bool {
+    insn.ops.iter().any(|op| matches!(op, OpInfo::Rm(_)))
+}
+
+/// the immediates of an instruction are classified by kind, and an instruction may contain at most one of each kind.
+fn imm_kind(op: &OpInfo) -> Option<&'static str> {
+    match op {
+        OpInfo::Imm(imm) if imm.encoded_size == OpSizeInfo::SZ_ALWAYS_8 => Some("8-bit immediate"),
+        OpInfo::Imm(_) => Some("full sized immediate"),
+        OpInfo::Rel(_) => Some("relative offset"),
+        OpInfo::MemOffset(_) => Some("memory offset"),
+        _ => None,
+    }
+}
+
+/// checks the invariants of a single instruction which don't depend on how its slot is identified.
+fn validate_insn(
+    map: &str,
+    opcode: u8,
+    location: &str,
+    insn: &RegularInsnInfo,
+    violations: &mut Vec<String>,
+) {
+    if insn.is_unsupported() {
+        return;
+    }
+    let imm_kinds: Vec<&'static str> = insn.ops.iter().filter_map(imm_kind).collect();
+    for (i, kind) in imm_kinds.iter().enumerate() {
+        if imm_kinds[..i].contains(kind) {
+            violations.push(format!(
+                "{}: {} has more than one {}",
+                location, insn.mnemonic, kind
+            ));
+        }
+    }
+    let has_opcode_reg = insn.ops.iter().any(|op| {
+        matches!(
+            op,
+            OpInfo::Reg(RegOpInfo {
+                encoding: RegEncoding::Opcode,
+                ..
+            })
+        )
+    });
+    if has_opcode_reg && !reference_has_opcode_reg(map, opcode) {
+        violations.push(format!(
+            "{}: {} has an opcode register operand, but the opcode doesn't encode a register",
+            location, insn.mnemonic
+        ));
+    }
+    let has_cond = insn.ops.iter().any(|op| matches!(op, OpInfo::Cond));
+    if has_cond && !reference_is_conditional(map, opcode) {
+        violations.push(format!(
+            "{}: {} has a condition operand, but the opcode is not part of a conditional family",
+            location, insn.mnemonic
+        ));
+    }
+}
+
+/// checks the invariants of the opcode byte table of the given map. returns a description of each violation, prefixed
+/// by its opcode address.
+pub fn validate_table(map: &str, table: &[InsnInfo]) -> Vec<String> {
+    let mut violations = Vec::new();
+    if table.len() != 0x100 {
+        violations.push(format!(
+            "{}: the table has {} entries instead of 256",
+            map,
+            table.len()
+        ));
+    }
+    for (opcode, insn_info) in table.iter().enumerate().take(0x100) {
+        let opcode = opcode as u8;
+        let location = format!("{} {:#04x}", map, opcode);
+        let has_modrm = reference_has_modrm(map, opcode);
+        match insn_info {
+            InsnInfo::Regular(insn) => {
+                validate_insn(map, opcode, &location, insn, &mut violations);
+                if insn.is_unsupported() {
+                    continue;
+                }
+                if insn.uses_modrm() && !has_modrm {
+                    violations.push(format!(
+                        "{}: {} has modrm operands, but the opcode has no modrm byte",
+                        location, insn.mnemonic
+                    ));
+                }
+                if !insn.uses_modrm() && has_modrm {
+                    violations.push(format!(
+                        "{}: {} has no modrm operands, but the opcode has a modrm byte",
+                        location, insn.mnemonic
+                    ));
+                }
+            }
+            InsnInfo::ModrmRegOpcodeExt(modrm_reg_opcode_ext) => {
+                if !has_modrm {
+                    violations.push(format!(
+                        "{}: the opcode is extended by the modrm reg field, but has no modrm byte",
+                        location
+                    ));
+                }
+                for (reg_value, insn) in modrm_reg_opcode_ext.by_reg_value.iter().enumerate() {
+                    let location = format!("{} /{}", location, reg_value);
+                    validate_insn(map, opcode, &location, insn, &mut violations);
+                    if !insn.is_unsupported() && !insn_has_rm_operand(insn) {
+                        violations.push(format!(
+                            "{}: {} is neither unsupported nor uses the modrm rm field",
+                            location, insn.mnemonic
+                        ));
+                    }
+                }
+            }
+            InsnInfo::RepPrefixModrmExt(rep_prefix_modrm_ext) => {
+                if !has_modrm {
+                    violations.push(format!(
+                        "{}: the opcode is extended by the modrm byte, but has no modrm byte",
+                        location
+                    ));
+                }
+                validate_insn(
+                    map,
+                    opcode,
+                    &location,
+                    &rep_prefix_modrm_ext.default,
+                    &mut violations,
+                );
+                for (i, rep_prefix_override) in rep_prefix_modrm_ext.overrides.iter().enumerate() {
+                    let override_location = format!(
+                        "{} with F3 prefix and modrm {:#04x}",
+                        location, rep_prefix_override.modrm
+                    );
+                    validate_insn(
+                        map,
+                        opcode,
+                        &override_location,
+                        &rep_prefix_override.insn,
+                        &mut violations,
+                    );
+                    if rep_prefix_modrm_ext.overrides[..i]
+                        .iter()
+                        .any(|x| x.modrm == rep_prefix_override.modrm)
+                    {
+                        violations.push(format!(
+                            "{}: the modrm byte is overridden more than once",
+                            override_location
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        first_opcode_byte_table::gen_first_opcode_byte_table,
+        second_opcode_byte_table::gen_second_opcode_byte_table,
+    };
+
+    fn insn(mnemonic: Mnemonic, ops: Ops) -> RegularInsnInfo {
+        RegularInsnInfo {
+            mnemonic,
+            ops,
+            rep_kind: RepKind::None,
+            lockable: false,
+        }
+    }
+
+    /// the violations of the real table of the given map after replacing the entry of the given opcode.
+    fn violations_with(map: &str, opcode: u8, insn_info: InsnInfo) -> Vec<String> {
+        let mut table = if map == MAP_PRIMARY {
+            gen_first_opcode_byte_table().unwrap()
+        } else {
+            gen_second_opcode_byte_table().unwrap()
+        };
+        table[opcode as usize] = insn_info;
+        validate_table(map, &table)
+    }
+
+    #[test]
+    fn real_tables_have_no_violations() {
+        assert_eq!(
+            validate_table(MAP_PRIMARY, &gen_first_opcode_byte_table().unwrap()),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            validate_table(MAP_0F, &gen_second_opcode_byte_table().unwrap()),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn injected_violations_are_reported() {
+        let unsupported_by_reg_value = || std::array::from_fn(|_| RegularInsnInfo::UNSUPPORTED);
+        let mut by_reg_value = unsupported_by_reg_value();
+        by_reg_value[0] = insn("add", &[OpInfo::IMM_8_NO_EXT]);
+        let endbr64 = RepPrefixModrmOverride {
+            modrm: 0xfa,
+            insn: insn("endbr64", &[]),
+        };
+        let cases = [
+            (
+                MAP_PRIMARY,
+                0x04,
+                InsnInfo::Regular(insn(
+                    "add",
+                    &[OpInfo::AL, OpInfo::IMM_8_NO_EXT, OpInfo::IMM_8_NO_EXT],
+                )),
+                "primary 0x04: add has more than one 8-bit immediate",
+            ),
+            (
+                MAP_PRIMARY,
+                0x04,
+                InsnInfo::Regular(insn("add", &[OpInfo::R_OPCODE_8])),
+                "primary 0x04: add has an opcode register operand, but the opcode doesn't encode a register",
+            ),
+            (
+                MAP_PRIMARY,
+                0x04,
+                InsnInfo::Regular(insn("add", &[OpInfo::Cond])),
+                "primary 0x04: add has a condition operand, but the opcode is not part of a conditional family",
+            ),
+            (
+                MAP_PRIMARY,
+                0x04,
+                InsnInfo::Regular(insn("add", &[OpInfo::RM_8])),
+                "primary 0x04: add has modrm operands, but the opcode has no modrm byte",
+            ),
+            (
+                MAP_PRIMARY,
+                0x00,
+                InsnInfo::Regular(insn("add", &[])),
+                "primary 0x00: add has no modrm operands, but the opcode has a modrm byte",
+            ),
+            (
+                MAP_PRIMARY,
+                0x04,
+                InsnInfo::ModrmRegOpcodeExt(ModrmRegOpcodeExtInsnInfo {
+                    by_reg_value: unsupported_by_reg_value(),
+                }),
+                "primary 0x04: the opcode is extended by the modrm reg field, but has no modrm byte",
+            ),
+            (
+                MAP_PRIMARY,
+                0x80,
+                InsnInfo::ModrmRegOpcodeExt(ModrmRegOpcodeExtInsnInfo { by_reg_value }),
+                "primary 0x80 /0: add is neither unsupported nor uses the modrm rm field",
+            ),
+            (
+                MAP_PRIMARY,
+                0x04,
+                InsnInfo::RepPrefixModrmExt(RepPrefixModrmExtInsnInfo {
+                    default: RegularInsnInfo::UNSUPPORTED,
+                    overrides: &[],
+                }),
+                "primary 0x04: the opcode is extended by the modrm byte, but has no modrm byte",
+            ),
+            (
+                MAP_0F,
+                0x1e,
+                InsnInfo::RepPrefixModrmExt(RepPrefixModrmExtInsnInfo {
+                    default: RegularInsnInfo::UNSUPPORTED,
+                    overrides: vec![endbr64.clone(), endbr64].leak(),
+                }),
+                "0f 0x1e with F3 prefix and modrm 0xfa: the modrm byte is overridden more than once",
+            ),
+        ];
+        for (map, opcode, insn_info, expected_violation) in cases {
+            assert_eq!(
+                violations_with(map, opcode, insn_info),
+                [expected_violation]
+            );
+        }
+
+        let mut short_table = gen_first_opcode_byte_table().unwrap();
+        short_table.pop();
+        assert_eq!(
+            validate_table(MAP_PRIMARY, &short_table),
+            ["primary: the table has 255 entries instead of 256"]
+        );
+    }
+}
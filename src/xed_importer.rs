@@ -0,0 +1,849 @@
+//! importer for instruction definitions written in the format of the XED datafiles.
+//!
+//! the input is a list of records, each of which describes a single instruction class:
+//!
+//! ```text
+//! {
+//! ICLASS     : CPUID
+//! ATTRIBUTES :
+//! PATTERN    : 0x0F 0xA2
+//! OPERANDS   : REG0=XED_REG_EAX:rw:SUPP REG1=XED_REG_EBX:w:SUPP REG2=XED_REG_ECX:rw:SUPP REG3=XED_REG_EDX:w:SUPP
+//! }
+//! ```
+//!
+//! a record may contain several `PATTERN`/`OPERANDS` pairs, one for each form of the instruction. other keys are
+//! ignored. each form is converted to an instruction definition in the format of `insn_defs.toml`, and every pattern
+//! token or operand which can't be represented by the table types is reported instead of being silently dropped.
+
+use std::collections::HashMap;
+
+use crate::{insn_defs::*, table_types::*};
+
+/// a single `PATTERN`/`OPERANDS` pair of a record.
+struct XedForm {
+    line: usize,
+    pattern: String,
+    operands: String,
+}
+
+struct XedRecord {
+    line: usize,
+    iclass: String,
+    attributes: Vec<String>,
+    forms: Vec<XedForm>,
+}
+
+fn parse_records(text: &str) -> Result<Vec<XedRecord>, String> {
+    let mut records = Vec::new();
+    let mut cur_record: Option<XedRecord> = None;
+    let mut cur_pattern: Option<(usize, String)> = None;
+    for (line_index, line) in text.lines().enumerate() {
+        let line_number = line_index + 1;
+        let line = match line.find('#') {
+            Some(comment_start) => &line[..comment_start],
+            None => line,
+        }
+        .trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line == "{" {
+            if cur_record.is_some() {
+                return Err(format!("line {}: nested record", line_number));
+            }
+            cur_record = Some(XedRecord {
+                line: line_number,
+                iclass: String::new(),
+                attributes: Vec::new(),
+                forms: Vec::new(),
+            });
+            continue;
+        }
+        let record = cur_record
+            .as_mut()
+            .ok_or_else(|| format!("line {}: content outside of a record", line_number))?;
+        if line == "}" {
+            if cur_pattern.is_some() {
+                return Err(format!("line {}: pattern without operands", line_number));
+            }
+            if record.iclass.is_empty() {
+                return Err(format!("line {}: record without an iclass", record.line));
+            }
+            records.push(cur_record.take().unwrap());
+            continue;
+        }
+        let (key, value) = line
+            .split_once(':')
+            .ok_or_else(|| format!("line {}: expected `KEY : VALUE`", line_number))?;
+        let value = value.trim().to_string();
+        match key.trim() {
+            "ICLASS" => record.iclass = value,
+            "ATTRIBUTES" => {
+                record.attributes = value.split_whitespace().map(String::from).collect()
+            }
+            "PATTERN" => {
+                if cur_pattern.is_some() {
+                    return Err(format!("line {}: pattern without operands", line_number));
+                }
+                cur_pattern = Some((line_number, value));
+            }
+            "OPERANDS" => {
+                let (pattern_line, pattern) = cur_pattern
+                    .take()
+                    .ok_or_else(|| format!("line {}: operands without a pattern", line_number))?;
+                record.forms.push(XedForm {
+                    line: pattern_line,
+                    pattern,
+                    operands: value,
+                });
+            }
+            _ => {}
+        }
+    }
+    if let Some(record) = cur_record {
+        return Err(format!("line {}: unterminated record", record.line));
+    }
+    Ok(records)
+}
+
+/// the constraint that a form places on the modrm mod field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ModConstraint {
+    None,
+    Register,
+    Memory,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ImmToken {
+    Uimm8,
+    Simm8,
+    Uimm16,
+    Simmz,
+    Uimmv,
+}
+
+/// the information extracted from the pattern of a form.
+struct Pattern {
+    map: &'static str,
+    opcodes: Vec<u8>,
+    /// whether the low 3 bits of the opcode encode a register operand.
+    has_opcode_reg: bool,
+    mod_constraint: ModConstraint,
+    modrm_reg: Option<u8>,
+    /// the full modrm byte, for forms which are further identified by an F3 prefix and the modrm byte.
+    rep_prefix_modrm: Option<u8>,
+    imms: Vec<ImmToken>,
+    rel_size: Option<OpSize>,
+    has_mem_offset: bool,
+    default_64: bool,
+}
+
+fn parse_modrm_field(token: &str, field_name: &str) -> Result<Option<u8>, String> {
+    let value = &token[field_name.len() + 1..token.len() - 1];
+    match value.strip_prefix("0b") {
+        Some(bits) => u8::from_str_radix(bits, 2)
+            .map(Some)
+            .map_err(|_| format!("invalid `{}` field value in `{}`", field_name, token)),
+        None => Ok(None),
+    }
+}
+
+fn parse_pattern(pattern: &str) -> Result<Pattern, String> {
+    let mut opcode_bytes = Vec::new();
+    // the high 5 bits of the last opcode byte, for opcodes whose low 3 bits encode a register
+    let mut opcode_reg_base = None;
+    let mut has_opcode_reg = false;
+    let mut mod_constraint = ModConstraint::None;
+    let mut modrm_reg = None;
+    let mut modrm_rm = None;
+    let mut has_f3_prefix = false;
+    let mut imms = Vec::new();
+    let mut rel_size = None;
+    let mut has_mem_offset = false;
+    let mut default_64 = false;
+    for token in pattern.split_whitespace() {
+        if let Some(hex) = token.strip_prefix("0x") {
+            let byte = u8::from_str_radix(hex, 16)
+                .map_err(|_| format!("invalid opcode byte `{}`", token))?;
+            opcode_bytes.push(byte);
+            continue;
+        }
+        if let Some(bits) = token.strip_prefix("0b") {
+            let bits = bits.replace('_', "");
+            let value = u8::from_str_radix(&bits, 2)
+                .map_err(|_| format!("invalid opcode bits `{}`", token))?;
+            match bits.len() {
+                8 => opcode_bytes.push(value),
+                5 => opcode_reg_base = Some(value << 3),
+                _ => return Err(format!("unsupported partial opcode byte `{}`", token)),
+            }
+            continue;
+        }
+        match token {
+            "SRM[rrr]" if opcode_reg_base.is_some() => has_opcode_reg = true,
+            "MODRM()" | "no_refining_prefix" => {}
+            "MOD=3" => mod_constraint = ModConstraint::Register,
+            "MOD!=3" => mod_constraint = ModConstraint::Memory,
+            "f3_refining_prefix" => has_f3_prefix = true,
+            "UIMM8()" | "UIMM8_1()" => imms.push(ImmToken::Uimm8),
+            "SIMM8()" => imms.push(ImmToken::Simm8),
+            "UIMM16()" => imms.push(ImmToken::Uimm16),
+            "SIMMz()" => imms.push(ImmToken::Simmz),
+            "UIMMv()" => imms.push(ImmToken::Uimmv),
+            "BRDISP8()" => rel_size = Some(OpSize::S8),
+            "BRDISPz()" => rel_size = Some(OpSize::S32),
+            "MEMDISPv()" => has_mem_offset = true,
+            "DF64()" => default_64 = true,
+            _ if token.starts_with("MOD[") && token.ends_with(']') => {}
+            _ if token.starts_with("REG[") && token.ends_with(']') => {
+                modrm_reg = parse_modrm_field(token, "REG")?
+            }
+            _ if token.starts_with("RM[") && token.ends_with(']') => {
+                modrm_rm = parse_modrm_field(token, "RM")?
+            }
+            _ => return Err(format!("unsupported pattern token `{}`", token)),
+        }
+    }
+    if let Some(base) = opcode_reg_base {
+        if !has_opcode_reg {
+            return Err("partial opcode byte without `SRM[rrr]`".to_string());
+        }
+        opcode_bytes.push(base);
+    }
+    let (map, opcode) = match opcode_bytes.as_slice() {
+        [opcode] => (MAP_PRIMARY, *opcode),
+        [0x0f, opcode] => (MAP_0F, *opcode),
+        _ => {
+            return Err(format!(
+                "opcode bytes {:02x?} are not in a supported opcode map",
+                opcode_bytes
+            ))
+        }
+    };
+    let opcodes = if has_opcode_reg {
+        (opcode..opcode + 8).collect()
+    } else {
+        vec![opcode]
+    };
+    let rep_prefix_modrm = match (modrm_rm, has_f3_prefix) {
+        (None, false) => None,
+        (Some(rm), true) => match (mod_constraint, modrm_reg) {
+            (ModConstraint::Register, Some(reg)) => {
+                modrm_reg = None;
+                mod_constraint = ModConstraint::None;
+                Some(0xc0 | (reg << 3) | rm)
+            }
+            _ => return Err("a fixed modrm rm field requires fixed mod and reg fields".to_string()),
+        },
+        (Some(_), false) => {
+            return Err("a fixed modrm rm field is only supported with an F3 prefix".to_string())
+        }
+        (None, true) => {
+            return Err(
+                "an F3 refining prefix is only supported with a fixed modrm byte".to_string(),
+            )
+        }
+    };
+    Ok(Pattern {
+        map,
+        opcodes,
+        has_opcode_reg,
+        mod_constraint,
+        modrm_reg,
+        rep_prefix_modrm,
+        imms,
+        rel_size,
+        has_mem_offset,
+        default_64,
+    })
+}
+
+fn op_size_info_of_width(width: &str, default_64: bool) -> Result<OpSizeInfo, String> {
+    match width {
+        "b" | "8" => Ok(OpSizeInfo::SZ_ALWAYS_8),
+        "w" | "16" => Ok(OpSizeInfo::SZ_ALWAYS_16),
+        "d" | "32" => Ok(OpSizeInfo::SZ_ALWAYS_32),
+        "q" | "64" => Ok(OpSizeInfo::SZ_ALWAYS_64),
+        "v" if default_64 => Ok(OpSizeInfo::SZ_16_32_64_DEF_64),
+        "v" => Ok(OpSizeInfo::SZ_16_32_64_DEF_32),
+        "z" => Ok(OpSizeInfo::SZ_IMM_ENCODING_16_32),
+        "y" => Ok(OpSizeInfo {
+            with_operand_size_override: OpSize::S32,
+            mode_32: OpSize::S32,
+            mode_64: OpSize::S32,
+            mode_64_with_rex_w: OpSize::S64,
+        }),
+        _ => Err(format!("unsupported operand width `{}`", width)),
+    }
+}
+
+fn specific_reg_of_xed_reg(name: &str) -> Option<(SpecificReg, OpSizeInfo)> {
+    let (reg, size) = match name {
+        "AL" => (SpecificReg::Rax, OpSizeInfo::SZ_ALWAYS_8),
+        "AX" => (SpecificReg::Rax, OpSizeInfo::SZ_ALWAYS_16),
+        "EAX" => (SpecificReg::Rax, OpSizeInfo::SZ_ALWAYS_32),
+        "RAX" => (SpecificReg::Rax, OpSizeInfo::SZ_ALWAYS_64),
+        "CL" => (SpecificReg::Rcx, OpSizeInfo::SZ_ALWAYS_8),
+        "CX" => (SpecificReg::Rcx, OpSizeInfo::SZ_ALWAYS_16),
+        "ECX" => (SpecificReg::Rcx, OpSizeInfo::SZ_ALWAYS_32),
+        "RCX" => (SpecificReg::Rcx, OpSizeInfo::SZ_ALWAYS_64),
+        "DL" => (SpecificReg::Rdx, OpSizeInfo::SZ_ALWAYS_8),
+        "DX" => (SpecificReg::Rdx, OpSizeInfo::SZ_ALWAYS_16),
+        "EDX" => (SpecificReg::Rdx, OpSizeInfo::SZ_ALWAYS_32),
+        "RDX" => (SpecificReg::Rdx, OpSizeInfo::SZ_ALWAYS_64),
+        "BL" => (SpecificReg::Rbx, OpSizeInfo::SZ_ALWAYS_8),
+        "BX" => (SpecificReg::Rbx, OpSizeInfo::SZ_ALWAYS_16),
+        "EBX" => (SpecificReg::Rbx, OpSizeInfo::SZ_ALWAYS_32),
+        "RBX" => (SpecificReg::Rbx, OpSizeInfo::SZ_ALWAYS_64),
+        "R11" => (SpecificReg::R11, OpSizeInfo::SZ_ALWAYS_64),
+        _ => return None,
+    };
+    Some((reg, size))
+}
+
+/// whether the given operand only describes state which the table types don't model, like the instruction pointer,
+/// the flags or the stack, and can thus be dropped when it is suppressed.
+fn is_unmodeled_suppressed_operand(name: &str, value: Option<&str>) -> bool {
+    match (name, value) {
+        ("BASE0" | "BASE1" | "SEG0" | "SEG1", _) => true,
+        (_, Some("rIP()" | "rFLAGS()" | "ArSP()" | "SrSP()")) => true,
+        (_, Some(value)) => matches!(
+            value,
+            "XED_REG_RIP"
+                | "XED_REG_EIP"
+                | "XED_REG_IP"
+                | "XED_REG_RFLAGS"
+                | "XED_REG_EFLAGS"
+                | "XED_REG_FLAGS"
+                | "XED_REG_STACKPUSH"
+                | "XED_REG_STACKPOP"
+        ),
+        _ => false,
+    }
+}
+
+/// converts a single operand of a form. returns `None` for operands which should be dropped.
+fn convert_operand(
+    operand: &str,
+    pattern: &Pattern,
+    imms: &mut impl Iterator<Item = ImmToken>,
+    prev_op_size: Option<&OpSizeInfo>,
+) -> Result<Option<OpInfo>, String> {
+    let mut parts = operand.split(':');
+    let head = parts.next().unwrap();
+    let (name, value) = match head.split_once('=') {
+        Some((name, value)) => (name, Some(value)),
+        None => (head, None),
+    };
+    let attrs: Vec<&str> = parts.collect();
+    let is_suppressed = attrs.contains(&"SUPP");
+    // the first attribute is the read/write action, and the width is the first one after it which is not a visibility
+    let width = attrs
+        .iter()
+        .skip(1)
+        .copied()
+        .find(|attr| !matches!(*attr, "SUPP" | "EXPL" | "IMPL"));
+    let size_of_width = || match width {
+        Some(width) => op_size_info_of_width(width, pattern.default_64),
+        None => Err(format!("operand `{}` has no width", operand)),
+    };
+
+    if is_suppressed && is_unmodeled_suppressed_operand(name, value) {
+        return Ok(None);
+    }
+    if name.starts_with("REG") {
+        let value = value.ok_or_else(|| format!("register operand `{}` has no value", operand))?;
+        if let Some(xed_reg) = value.strip_prefix("XED_REG_") {
+            return match specific_reg_of_xed_reg(xed_reg) {
+                Some((reg, size)) => Ok(Some(OpInfo::SpecificReg(SpecificRegOpInfo { reg, size }))),
+                None => Err(format!("unsupported register `{}`", value)),
+            };
+        }
+        if let Some(reg_name) = value.strip_prefix("Or").and_then(|x| x.strip_suffix("()")) {
+            let reg = match reg_name {
+                "AX" => SpecificReg::Rax,
+                "CX" => SpecificReg::Rcx,
+                "DX" => SpecificReg::Rdx,
+                "BX" => SpecificReg::Rbx,
+                _ => return Err(format!("unsupported register `{}`", value)),
+            };
+            return Ok(Some(OpInfo::SpecificReg(SpecificRegOpInfo {
+                reg,
+                size: op_size_info_of_width("v", pattern.default_64)?,
+            })));
+        }
+        if let Some(gpr) = value.strip_prefix("GPR").and_then(|x| x.strip_suffix("()")) {
+            let (gpr_width, kind) = gpr
+                .split_once('_')
+                .ok_or_else(|| format!("unsupported register `{}`", value))?;
+            let size = op_size_info_of_width(gpr_width, pattern.default_64)?;
+            return match kind {
+                "R" => Ok(Some(OpInfo::Reg(RegOpInfo {
+                    encoding: RegEncoding::Modrm,
+                    size,
+                }))),
+                "B" => Ok(Some(OpInfo::Rm(size))),
+                "SB" if pattern.has_opcode_reg => Ok(Some(OpInfo::Reg(RegOpInfo {
+                    encoding: RegEncoding::Opcode,
+                    size,
+                }))),
+                _ => Err(format!("unsupported register `{}`", value)),
+            };
+        }
+        return Err(format!("unsupported register `{}`", value));
+    }
+    match name {
+        "MEM0" | "MEM1" if is_suppressed => Ok(Some(OpInfo::Implicit(size_of_width()?))),
+        "MEM0" if pattern.has_mem_offset => Ok(Some(OpInfo::MemOffset(MemOffsetOpInfo {
+            mem_operand_size: size_of_width()?,
+        }))),
+        "MEM0" => Ok(Some(OpInfo::Rm(size_of_width()?))),
+        "AGEN" => Ok(Some(OpInfo::Rm(op_size_info_of_width(
+            "v",
+            pattern.default_64,
+        )?))),
+        "IMM0" | "IMM1" => {
+            let imm = imms
+                .next()
+                .ok_or_else(|| format!("operand `{}` has no immediate in the pattern", operand))?;
+            // sign extended immediates are extended to the size of the operand that they are combined with
+            let extended_size = match prev_op_size {
+                Some(size) => size.clone(),
+                None => op_size_info_of_width("v", pattern.default_64)?,
+            };
+            Ok(Some(match imm {
+                ImmToken::Uimm8 => OpInfo::IMM_8_NO_EXT,
+                ImmToken::Uimm16 => OpInfo::IMM_16_NO_EXT,
+                ImmToken::Simm8 => OpInfo::Imm(ImmOpInfo {
+                    encoded_size: OpSizeInfo::SZ_ALWAYS_8,
+                    extended_size,
+                    extend_kind: ImmExtendKind::SignExtend,
+                }),
+                ImmToken::Simmz => OpInfo::Imm(ImmOpInfo {
+                    encoded_size: OpSizeInfo::SZ_IMM_ENCODING_16_32,
+                    extended_size,
+                    extend_kind: ImmExtendKind::SignExtend,
+                }),
+                ImmToken::Uimmv => {
+                    let size = op_size_info_of_width("v", pattern.default_64)?;
+                    OpInfo::Imm(ImmOpInfo {
+                        encoded_size: size.clone(),
+                        extended_size: size,
+                        extend_kind: ImmExtendKind::ZeroExtend,
+                    })
+                }
+            }))
+        }
+        "RELBR" => match pattern.rel_size {
+            Some(OpSize::S8) => Ok(Some(OpInfo::REL_8)),
+            Some(_) => Ok(Some(OpInfo::REL_32)),
+            None => Err(format!(
+                "operand `{}` has no displacement in the pattern",
+                operand
+            )),
+        },
+        _ => Err(format!("unsupported operand `{}`", operand)),
+    }
+}
+
+fn op_size(op: &OpInfo) -> Option<&OpSizeInfo> {
+    match op {
+        OpInfo::Reg(reg) => Some(&reg.size),
+        OpInfo::Rm(size) => Some(size),
+        OpInfo::SpecificReg(reg) => Some(&reg.size),
+        _ => None,
+    }
+}
+
+/// an instruction definition converted from a single form, along with the constraint that it places on the modrm mod
+/// field.
+struct ConvertedForm {
+    insn_def: InsnDef,
+    mod_constraint: ModConstraint,
+}
+
+fn convert_form(record: &XedRecord, form: &XedForm) -> Result<Vec<ConvertedForm>, String> {
+    let pattern = parse_pattern(&form.pattern)?;
+    let mut imms = pattern.imms.iter().copied();
+    let mut ops: Vec<OpInfo> = Vec::new();
+    for operand in form.operands.split_whitespace() {
+        let prev_op_size = ops.first().and_then(op_size);
+        if let Some(op) = convert_operand(operand, &pattern, &mut imms, prev_op_size)? {
+            ops.push(op);
+        }
+    }
+    if imms.next().is_some() {
+        return Err("the pattern has an immediate without a matching operand".to_string());
+    }
+    let lockable = record.attributes.iter().any(|attr| attr == "LOCKABLE");
+    Ok(pattern
+        .opcodes
+        .iter()
+        .map(|&opcode| ConvertedForm {
+            insn_def: InsnDef {
+                map: pattern.map.to_string(),
+                opcode,
+                modrm_reg: pattern.modrm_reg,
+                rep_prefix_modrm: pattern.rep_prefix_modrm,
+                mnemonic: record.iclass.to_lowercase(),
+                ops: ops.iter().cloned().map(OpDef::new).collect(),
+                rep_kind: RepKind::None,
+                lockable,
+            },
+            mod_constraint: pattern.mod_constraint,
+        })
+        .collect())
+}
+
+/// the result of importing a datafile.
+pub struct XedImport {
+    pub insn_defs: Vec<InsnDef>,
+    /// the line number and a description of each record or form which could not be represented, sorted by line.
+    pub unrepresentable: Vec<(usize, String)>,
+}
+
+/// the location of an instruction definition, which identifies the slot it is placed into.
+fn location_key(insn_def: &InsnDef) -> (String, u8, Option<u8>, Option<u8>) {
+    (
+        insn_def.map.clone(),
+        insn_def.opcode,
+        insn_def.modrm_reg,
+        insn_def.rep_prefix_modrm,
+    )
+}
+
+/// imports the records of a datafile in the XED format. fails if the datafile is malformed.
+pub fn import_xed(text: &str) -> Result<XedImport, String> {
+    let records = parse_records(text)?;
+    let mut unrepresentable = Vec::new();
+    // the converted forms of each slot, in order of first appearance
+    let mut slots: Vec<(usize, Vec<ConvertedForm>)> = Vec::new();
+    let mut slot_index_by_key = HashMap::new();
+    // repeated variants, like `REP_MOVSB`, are folded into the rep kind of the base instruction
+    let mut rep_variants = Vec::new();
+    for record in &records {
+        if let Some(base) = record.iclass.strip_prefix("REP_") {
+            rep_variants.push((record, base.to_lowercase(), RepKind::Rep));
+            continue;
+        }
+        if let Some(base) = record
+            .iclass
+            .strip_prefix("REPE_")
+            .or_else(|| record.iclass.strip_prefix("REPNE_"))
+        {
+            rep_variants.push((record, base.to_lowercase(), RepKind::RepeRepne));
+            continue;
+        }
+        for form in &record.forms {
+            match convert_form(record, form) {
+                Ok(converted_forms) => {
+                    for converted in converted_forms {
+                        let key = location_key(&converted.insn_def);
+                        let slot_index = *slot_index_by_key.entry(key).or_insert_with(|| {
+                            slots.push((form.line, Vec::new()));
+                            slots.len() - 1
+                        });
+                        slots[slot_index].1.push(converted);
+                    }
+                }
+                Err(err) => {
+                    unrepresentable.push((form.line, format!("{}: {}", record.iclass, err)))
+                }
+            }
+        }
+    }
+
+    let mut insn_defs = Vec::new();
+    for (line, forms) in slots {
+        let first = &forms[0].insn_def;
+        let location = first.location();
+        if forms
+            .iter()
+            .any(|form| form.insn_def.mnemonic != first.mnemonic || form.insn_def.ops != first.ops)
+        {
+            unrepresentable.push((
+                line,
+                format!(
+                    "{}: the forms of {} have different operands",
+                    first.mnemonic.to_uppercase(),
+                    location
+                ),
+            ));
+            continue;
+        }
+        let has_mod_constraint =
+            |constraint| forms.iter().any(|form| form.mod_constraint == constraint);
+        let covers_all_mod_values = has_mod_constraint(ModConstraint::None)
+            || (has_mod_constraint(ModConstraint::Register)
+                && has_mod_constraint(ModConstraint::Memory));
+        if !covers_all_mod_values {
+            unrepresentable.push((
+                line,
+                format!(
+                    "{}: {} is only defined for some values of the modrm mod field",
+                    first.mnemonic.to_uppercase(),
+                    location
+                ),
+            ));
+            continue;
+        }
+        insn_defs.push(forms.into_iter().next().unwrap().insn_def);
+    }
+
+    for (record, base, rep_kind) in rep_variants {
+        let mut matching_defs = insn_defs
+            .iter_mut()
+            .filter(|insn_def| insn_def.mnemonic == base)
+            .peekable();
+        if matching_defs.peek().is_none() {
+            unrepresentable.push((
+                record.line,
+                format!("{}: no base instruction `{}`", record.iclass, base),
+            ));
+        }
+        for insn_def in matching_defs {
+            insn_def.rep_kind = rep_kind.clone();
+        }
+    }
+
+    unrepresentable.sort_by_key(|(line, _)| *line);
+    Ok(XedImport {
+        insn_defs,
+        unrepresentable,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(operand: &str, pattern: &str) -> Result<Option<OpInfo>, String> {
+        let pattern = parse_pattern(pattern).unwrap();
+        let mut imms = pattern.imms.clone().into_iter();
+        convert_operand(operand, &pattern, &mut imms, None)
+    }
+
+    fn record(iclass: &str, pattern: &str, operands: &str) -> String {
+        format!(
+            "{{\nICLASS : {}\nPATTERN : {}\nOPERANDS : {}\n}}\n",
+            iclass, pattern, operands
+        )
+    }
+
+    #[test]
+    fn parse_pattern_extracts_the_slot() {
+        let pattern = parse_pattern("0x0F 0xA2").unwrap();
+        assert_eq!((pattern.map, pattern.opcodes), (MAP_0F, vec![0xa2]));
+
+        let pattern = parse_pattern("0b0101_0 SRM[rrr] DF64()").unwrap();
+        assert_eq!(pattern.map, MAP_PRIMARY);
+        assert_eq!(pattern.opcodes, (0x50..0x58).collect::<Vec<u8>>());
+        assert!(pattern.has_opcode_reg && pattern.default_64);
+
+        let pattern =
+            parse_pattern("0xF6 MOD[mm] MOD!=3 REG[0b000] RM[nnn] MODRM() UIMM8()").unwrap();
+        assert_eq!(pattern.modrm_reg, Some(0));
+        assert_eq!(pattern.mod_constraint, ModConstraint::Memory);
+        assert!(matches!(pattern.imms.as_slice(), [ImmToken::Uimm8]));
+
+        let pattern =
+            parse_pattern("0x0F 0x1E f3_refining_prefix MOD[0b11] MOD=3 REG[0b111] RM[0b010]")
+                .unwrap();
+        assert_eq!(pattern.rep_prefix_modrm, Some(0xfa));
+        assert_eq!(pattern.modrm_reg, None);
+        assert_eq!(pattern.mod_constraint, ModConstraint::None);
+    }
+
+    #[test]
+    fn parse_pattern_rejects_unrepresentable_patterns() {
+        for (pattern, expected_error) in [
+            (
+                "0x0F 0x38 0x00",
+                "opcode bytes [0f, 38, 00] are not in a supported opcode map",
+            ),
+            (
+                "0x0F 0x58 VEXVALID=1",
+                "unsupported pattern token `VEXVALID=1`",
+            ),
+            (
+                "0x0F 0x01 MOD[0b11] MOD=3 REG[0b000] RM[0b001]",
+                "a fixed modrm rm field is only supported with an F3 prefix",
+            ),
+            ("0b0101_0 DF64()", "partial opcode byte without `SRM[rrr]`"),
+        ] {
+            assert_eq!(
+                parse_pattern(pattern).err(),
+                Some(expected_error.to_string()),
+                "{}",
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    fn convert_operand_converts_modeled_operands() {
+        assert_eq!(
+            convert("REG0=GPRv_B():rw", "0x01 MODRM()"),
+            Ok(Some(OpInfo::RM_16_32_64_DEF_32))
+        );
+        assert_eq!(
+            convert("REG0=GPR8_R():r", "0x00 MODRM()"),
+            Ok(Some(OpInfo::R_MODRM_8))
+        );
+        assert_eq!(
+            convert("REG0=GPRv_SB():w", "0b0101_0 SRM[rrr] DF64()"),
+            Ok(Some(OpInfo::R_OPCODE_16_32_64_DEF_64))
+        );
+        assert_eq!(
+            convert("REG0=XED_REG_AL:rw:IMPL", "0x04 UIMM8()"),
+            Ok(Some(OpInfo::AL))
+        );
+        assert_eq!(
+            convert("IMM0:r:b", "0x04 UIMM8()"),
+            Ok(Some(OpInfo::IMM_8_NO_EXT))
+        );
+        assert_eq!(
+            convert("RELBR:r:b", "0x74 BRDISP8()"),
+            Ok(Some(OpInfo::REL_8))
+        );
+        assert_eq!(
+            convert("MEM0:r:v", "0xA1 MEMDISPv()"),
+            Ok(Some(OpInfo::MemOffset(MemOffsetOpInfo {
+                mem_operand_size: OpSizeInfo::SZ_16_32_64_DEF_32
+            })))
+        );
+        assert_eq!(
+            convert("REG0=XED_REG_RIP:w:SUPP", "0xE8 BRDISPz()"),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn convert_operand_rejects_unmodeled_operands() {
+        for (operand, expected_error) in [
+            ("REG0=XED_REG_XMM0:r", "unsupported register `XED_REG_XMM0`"),
+            ("REG0=GPRv_SB():w", "unsupported register `GPRv_SB()`"),
+            (
+                "IMM0:r:b",
+                "operand `IMM0:r:b` has no immediate in the pattern",
+            ),
+            ("PTR:r:p", "unsupported operand `PTR:r:p`"),
+        ] {
+            assert_eq!(
+                convert(operand, "0x90"),
+                Err(expected_error.to_string()),
+                "{}",
+                operand
+            );
+        }
+    }
+
+    #[test]
+    fn rep_variants_are_folded_into_their_base_instruction() {
+        let text = [
+            record(
+                "MOVSB",
+                "0xA4",
+                "MEM0:w:SUPP:b BASE0=ArDI():rw:SUPP MEM1:r:SUPP:b BASE1=ArSI():rw:SUPP",
+            ),
+            record("REP_MOVSB", "0xA4 f3_refining_prefix", "MEM0:w:SUPP:b"),
+            record("CMPSB", "0xA6", "MEM0:r:SUPP:b MEM1:r:SUPP:b"),
+            record("REPE_CMPSB", "0xA6 f3_refining_prefix", "MEM0:r:SUPP:b"),
+            record("REP_STOSB", "0xAA f3_refining_prefix", "MEM0:w:SUPP:b"),
+        ]
+        .concat();
+        let import = import_xed(&text).unwrap();
+        let rep_kinds: Vec<(&str, RepKind)> = import
+            .insn_defs
+            .iter()
+            .map(|insn_def| (insn_def.mnemonic.as_str(), insn_def.rep_kind.clone()))
+            .collect();
+        assert_eq!(
+            rep_kinds,
+            [("movsb", RepKind::Rep), ("cmpsb", RepKind::RepeRepne)]
+        );
+        assert_eq!(
+            import.unrepresentable,
+            [(21, "REP_STOSB: no base instruction `stosb`".to_string())]
+        );
+    }
+
+    #[test]
+    fn unrepresentable_forms_are_reported_by_line() {
+        let text = [
+            record("NOP", "0x90", ""),
+            record("VADDPS", "0x0F 0x58 VEXVALID=1", "REG0=XED_REG_XMM0:w"),
+            record("ONLY_REG", "0x0F 0x0D MOD[0b11] MOD=3 REG[rrr] RM[nnn] MODRM()", "REG0=GPRv_B():r"),
+            "{\nICLASS : DIFFERENT\nPATTERN : 0x0F 0x19 MOD[0b11] MOD=3 REG[rrr] RM[nnn] MODRM()\n\
+             OPERANDS : REG0=GPRv_B():r\nPATTERN : 0x0F 0x19 MOD[mm] MOD!=3 REG[rrr] RM[nnn] MODRM()\n\
+             OPERANDS : MEM0:r:b\n}\n"
+                .to_string(),
+        ]
+        .concat();
+        let import = import_xed(&text).unwrap();
+        assert_eq!(import.insn_defs.len(), 1);
+        assert_eq!(
+            import.unrepresentable,
+            [
+                (
+                    8,
+                    "VADDPS: unsupported pattern token `VEXVALID=1`".to_string()
+                ),
+                (
+                    13,
+                    "ONLY_REG: 0f 0x0d is only defined for some values of the modrm mod field"
+                        .to_string()
+                ),
+                (
+                    18,
+                    "DIFFERENT: the forms of 0f 0x19 have different operands".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn explicit_operands_are_written_kind_first() {
+        let import = import_xed(&record(
+            "UD1",
+            "0x0F 0xB9 MOD[mm] REG[rrr] RM[nnn] MODRM()",
+            "REG0=GPR32_R():r MEM0:r:d",
+        ))
+        .unwrap();
+        assert!(import.insn_defs[0].to_toml().contains(
+            "ops = [{ kind = \"reg\", info = { encoding = \"modrm\", size = \"SZ_ALWAYS_32\" } }, \
+             { kind = \"rm\", info = \"SZ_ALWAYS_32\" }]"
+        ));
+    }
+
+    #[test]
+    fn malformed_datafiles_are_reported_by_line() {
+        let cases = [
+            ("ICLASS : NOP\n", "line 1: content outside of a record"),
+            ("{\nICLASS : NOP\n{\n", "line 3: nested record"),
+            ("{\nICLASS NOP\n}\n", "line 2: expected `KEY : VALUE`"),
+            (
+                "{\nICLASS : NOP\nOPERANDS :\n}\n",
+                "line 3: operands without a pattern",
+            ),
+            (
+                "{\nICLASS : NOP\nPATTERN : 0x90\n}\n",
+                "line 4: pattern without operands",
+            ),
+            (
+                "\n{\nPATTERN : 0x90\nOPERANDS :\n}\n",
+                "line 2: record without an iclass",
+            ),
+            ("{\nICLASS : NOP\n", "line 1: unterminated record"),
+        ];
+        for (text, expected_err) in cases {
+            assert_eq!(
+                import_xed(text).err().as_deref(),
+                Some(expected_err),
+                "{:?}",
+                text
+            );
+        }
+    }
+}